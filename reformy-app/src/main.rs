@@ -1,8 +1,12 @@
-use std::{fmt::Display, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
 
 use crossterm::event::{self, Event};
-use ratatui::widgets::Widget;
 use reformy::FormRenderable;
+use reformy_core::{Form, FormErrors, FormRenderable, FormWidget};
 
 #[derive(Debug, Default)]
 struct Email(String);
@@ -13,39 +17,97 @@ impl Display for Email {
     }
 }
 
+reformy_core::impl_form_value!(Email);
+
 impl FromStr for Email {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.contains("@") {
             Ok(Email(s.to_string()))
         } else {
-            Err(())
+            Err("missing @".to_string())
         }
     }
 }
 
-use ratatui::widgets::StatefulWidgetRef;
-
-#[derive(Debug, Default, FormRenderable)]
+#[derive(Default, FormRenderable)]
+#[form(rename_all = "Title Case")]
 struct User {
+    #[form(validate = not_blank)]
     name: String,
+    /// Shown instead of the name when set.
+    #[form(placeholder = "none")]
+    nickname: Option<String>,
+    #[form(multiline(rows = 3))]
+    bio: String,
+    tags: Vec<String>,
+    labels: BTreeMap<String, String>,
+    verified: bool,
+    #[form(switch, help = "A monthly email about new features")]
+    newsletter: bool,
+    #[form(default = 18, min = 0, max = 120)]
     age: usize,
-    #[form(nested)]
     role: Role,
+    plan: Plan,
+    permissions: HashSet<Permission>,
+    #[form(placeholder = "name@example.com")]
     email: Email,
-    #[form(nested)]
+    #[form(secret)]
+    password: String,
     address: Address,
+    billing_address: Option<Address>,
+    location: Coordinates,
+}
+
+// Leaves out the password.
+impl std::fmt::Debug for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("User")
+            .field("name", &self.name)
+            .field("nickname", &self.nickname)
+            .field("bio", &self.bio)
+            .field("tags", &self.tags)
+            .field("labels", &self.labels)
+            .field("verified", &self.verified)
+            .field("newsletter", &self.newsletter)
+            .field("age", &self.age)
+            .field("role", &self.role)
+            .field("plan", &self.plan)
+            .field("permissions", &self.permissions)
+            .field("email", &self.email)
+            .field("address", &self.address)
+            .field("billing_address", &self.billing_address)
+            .field("location", &self.location)
+            .finish_non_exhaustive()
+    }
+}
+
+fn not_blank(text: &str) -> Result<(), String> {
+    if text.trim().is_empty() {
+        Err("must not be blank".to_string())
+    } else {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default, FormRenderable)]
 struct Address {
-    #[form(nested)]
+    #[form(readonly, default = "Earth".to_string())]
+    planet: String,
     whatever: Whatever,
     street: String,
     number: usize,
+    distance: Meters,
 }
 
+#[derive(Debug, Default, FormRenderable)]
+#[form(transparent)]
+struct Meters(f64);
+
+#[derive(Debug, Default, FormRenderable)]
+struct Coordinates(f64, f64);
+
 #[derive(Debug, Default, FormRenderable)]
 struct Whatever {
     foo: String,
@@ -58,38 +120,71 @@ enum Role {
     Guest {
         name: String,
         cool: String,
-        #[form(nested)]
         whatever: Whatever,
     },
+    Moderator(#[form(label = "channel")] String, usize),
     #[default]
     User,
 }
 
-fn main() {
-    let mut foo = User::form();
+#[derive(Debug, PartialEq, Eq, Hash, FormRenderable)]
+enum Permission {
+    Read,
+    Write,
+    Admin,
+}
+
+#[derive(Debug, Default, FormRenderable)]
+#[form(radio)]
+enum Plan {
+    #[default]
+    Free,
+    Pro,
+    Team {
+        seats: u32,
+    },
+}
+
+fn edit<T: FormRenderable>() -> Result<T, FormErrors> {
+    let mut form = T::form();
+    form.focus_first();
     let mut terminal = ratatui::init();
 
     loop {
         terminal
             .draw(|f| {
-                f.render_widget(&foo, f.area());
+                let area = f.area();
+                form.render(area, f.buffer_mut(), true);
             })
             .unwrap();
 
         if let Event::Key(key) = event::read().unwrap() {
             match key.code {
                 event::KeyCode::Esc => break,
-                key => {
+                event::KeyCode::BackTab => {
                     let input = tui_textarea::Input {
-                        key: key.into(),
+                        key: tui_textarea::Key::Tab,
+                        shift: true,
                         ..Default::default()
                     };
-                    foo.input(input);
+                    form.root_input(input, true);
+                }
+                _ => {
+                    form.root_input(key.into(), true);
                 }
             }
         }
     }
 
     ratatui::restore();
-    dbg!(foo.build());
+    form.try_build()
+}
+
+fn main() {
+    match edit::<User>() {
+        Ok(user) => {
+            dbg!(user);
+        }
+        Err(errors) => eprintln!("{}", errors),
+    }
 }
@@ -0,0 +1,108 @@
+use ratatui::{buffer::Buffer, layout::Rect};
+use tui_textarea::Input;
+
+use crate::{ErrorDisplay, FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+
+/// The form of a `Box<T>`, which edits the boxed value with the form of `T`.
+///
+/// The form is boxed as well, so recursive types such as
+/// `struct Node { next: Option<Box<Node>> }` get a form of finite size.
+pub struct BoxForm<T: FormRenderable> {
+    pub form: Box<T::Form>,
+}
+
+impl<T: FormRenderable> Default for BoxForm<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FormRenderable> BoxForm<T> {
+    pub fn new() -> Self {
+        Self {
+            form: Box::new(T::form()),
+        }
+    }
+}
+
+impl<T: FormRenderable> Form for BoxForm<T> {
+    type Output = Box<T>;
+
+    fn try_build(&self) -> Result<Self::Output, FormErrors> {
+        self.form.try_build().map(Box::new)
+    }
+
+    fn set_value(&mut self, value: &Self::Output) {
+        self.form.set_value(value)
+    }
+
+    fn focus_first_error(&mut self) -> bool {
+        self.form.focus_first_error()
+    }
+}
+
+impl<T: FormRenderable> FormWidget for BoxForm<T> {
+    fn input(&mut self, input: Input) -> bool {
+        self.form.input(input)
+    }
+
+    fn form_height(&self) -> u16 {
+        self.form.form_height()
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        self.form.render(area, buf, focused)
+    }
+
+    fn is_inline(&self) -> bool {
+        self.form.is_inline()
+    }
+
+    fn visible_error(&self) -> Option<String> {
+        self.form.visible_error()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.form.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.form.clear()
+    }
+
+    fn set_placeholder(&mut self, text: &str) {
+        self.form.set_placeholder(text)
+    }
+
+    fn blur(&mut self) {
+        self.form.blur()
+    }
+
+    fn focus_first(&mut self) {
+        self.form.focus_first()
+    }
+
+    fn focus_last(&mut self) {
+        self.form.focus_last()
+    }
+
+    fn focus_path(&self) -> FieldPath {
+        self.form.focus_path()
+    }
+
+    fn set_focus_segments(&mut self, path: &[String]) -> bool {
+        self.form.set_focus_segments(path)
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.form.set_error_display(display)
+    }
+}
+
+impl<T: FormRenderable> FormRenderable for Box<T> {
+    type Form = BoxForm<T>;
+
+    fn form() -> Self::Form {
+        BoxForm::new()
+    }
+}
@@ -0,0 +1,130 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{Form, FormErrors, FormRenderable, FormWidget};
+
+/// How a [`Checkbox`] shows its state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckboxStyle {
+    /// `[x]` or `[ ]`.
+    #[default]
+    Checkbox,
+    /// `on` or `off`, set with `#[form(switch)]`.
+    Switch,
+}
+
+/// The form of a `bool`, toggled with Space or Enter.
+///
+/// A cleared checkbox is unset rather than unchecked, which lets an
+/// `Option<bool>` build `None`. Once cleared, toggling cycles through
+/// checked, unchecked and unset.
+#[derive(Clone, Debug, Default)]
+pub struct Checkbox {
+    pub checked: bool,
+    pub style: CheckboxStyle,
+    /// Whether the checkbox holds no value, shown as `[-]`.
+    pub unset: bool,
+    /// Whether toggling can return to the unset state.
+    pub tristate: bool,
+}
+
+impl Checkbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A checkbox that renders as an on/off switch.
+    pub fn switch() -> Self {
+        Self {
+            style: CheckboxStyle::Switch,
+            ..Self::default()
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if self.unset {
+            self.unset = false;
+            self.checked = true;
+        } else if self.tristate && !self.checked {
+            self.unset = true;
+        } else {
+            self.checked = !self.checked;
+        }
+    }
+}
+
+impl Form for Checkbox {
+    type Output = bool;
+
+    fn try_build(&self) -> Result<bool, FormErrors> {
+        Ok(self.checked)
+    }
+
+    fn set_value(&mut self, value: &bool) {
+        self.checked = *value;
+        self.unset = false;
+    }
+}
+
+impl FormWidget for Checkbox {
+    fn input(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Char(' ') | Key::Enter => {
+                self.toggle();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn form_height(&self) -> u16 {
+        1
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        let text = match (self.style, self.checked) {
+            (CheckboxStyle::Checkbox, _) if self.unset => "[-]",
+            (CheckboxStyle::Switch, _) if self.unset => "unset",
+            (CheckboxStyle::Checkbox, true) => "[x]",
+            (CheckboxStyle::Checkbox, false) => "[ ]",
+            (CheckboxStyle::Switch, true) => "on",
+            (CheckboxStyle::Switch, false) => "off",
+        };
+        let style = if focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+
+        Paragraph::new(text)
+            .style(style)
+            .render(Rect { height: 1, ..area }, buf);
+    }
+
+    fn is_inline(&self) -> bool {
+        true
+    }
+
+    fn is_empty(&self) -> bool {
+        self.unset
+    }
+
+    fn clear(&mut self) {
+        self.checked = false;
+        self.unset = true;
+        self.tristate = true;
+    }
+}
+
+impl FormRenderable for bool {
+    type Form = Checkbox;
+
+    fn form() -> Self::Form {
+        Checkbox::new()
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{Form, FormErrors, FormRenderable, FormWidget};
+
+/// A unit-only enum, implemented by `#[derive(FormRenderable)]` for enums
+/// without fields, so collections of it can be picked from a list.
+pub trait Choice: Sized {
+    /// The label of every variant, in declaration order.
+    const LABELS: &'static [&'static str];
+
+    /// The variant at `index` in [`Choice::LABELS`].
+    ///
+    /// # Panics
+    /// If `index` is out of range.
+    fn from_index(index: usize) -> Self;
+
+    /// The index of this variant in [`Choice::LABELS`].
+    fn index(&self) -> usize;
+}
+
+/// A collection of [`Choice`]s, edited with a [`MultiSelect`].
+pub trait ChoiceSet: Sized {
+    type Choice: Choice;
+
+    fn from_choices(choices: impl Iterator<Item = Self::Choice>) -> Self;
+
+    /// The indices of the choices in the collection.
+    fn indices(&self) -> Vec<usize>;
+}
+
+impl<T: Choice> ChoiceSet for Vec<T> {
+    type Choice = T;
+
+    fn from_choices(choices: impl Iterator<Item = T>) -> Self {
+        choices.collect()
+    }
+
+    fn indices(&self) -> Vec<usize> {
+        self.iter().map(Choice::index).collect()
+    }
+}
+
+impl<T: Choice + Eq + Hash, S: BuildHasher + Default> ChoiceSet for HashSet<T, S> {
+    type Choice = T;
+
+    fn from_choices(choices: impl Iterator<Item = T>) -> Self {
+        choices.collect()
+    }
+
+    fn indices(&self) -> Vec<usize> {
+        self.iter().map(Choice::index).collect()
+    }
+}
+
+impl<T: Choice + Ord> ChoiceSet for BTreeSet<T> {
+    type Choice = T;
+
+    fn from_choices(choices: impl Iterator<Item = T>) -> Self {
+        choices.collect()
+    }
+
+    fn indices(&self) -> Vec<usize> {
+        self.iter().map(Choice::index).collect()
+    }
+}
+
+/// A list of labelled checkboxes, Up/Down move the cursor and Space or Enter
+/// toggles the checkbox under it.
+#[derive(Clone, Debug)]
+pub struct CheckboxGroup {
+    pub labels: Vec<&'static str>,
+    pub checked: Vec<bool>,
+    pub cursor: usize,
+}
+
+impl CheckboxGroup {
+    pub fn new(labels: Vec<&'static str>) -> Self {
+        Self {
+            checked: vec![false; labels.len()],
+            labels,
+            cursor: 0,
+        }
+    }
+
+    /// The indices of the checked boxes.
+    pub fn checked_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.checked.len()).filter(|&idx| self.checked[idx])
+    }
+
+    /// Checks exactly the boxes at `indices`.
+    pub fn set_checked(&mut self, indices: impl IntoIterator<Item = usize>) {
+        self.checked.fill(false);
+        for idx in indices {
+            if let Some(checked) = self.checked.get_mut(idx) {
+                *checked = true;
+            }
+        }
+    }
+
+    pub fn input(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Up if self.cursor > 0 => {
+                self.cursor -= 1;
+                true
+            }
+            Key::Down if self.cursor + 1 < self.labels.len() => {
+                self.cursor += 1;
+                true
+            }
+            Key::Char(' ') | Key::Enter if self.cursor < self.checked.len() => {
+                self.checked[self.cursor] = !self.checked[self.cursor];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn height(&self) -> u16 {
+        self.labels.len() as u16
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        for (idx, label) in self.labels.iter().enumerate() {
+            let Some(y) = area
+                .y
+                .checked_add(idx as u16)
+                .filter(|&y| y < area.bottom())
+            else {
+                break;
+            };
+            let marker = if self.checked[idx] { "[x]" } else { "[ ]" };
+            let style = if focused && idx == self.cursor {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Paragraph::new(format!("{} {}", marker, label))
+                .style(style)
+                .render(
+                    Rect {
+                        y,
+                        height: 1,
+                        ..area
+                    },
+                    buf,
+                );
+        }
+    }
+}
+
+/// The form of a [`ChoiceSet`], a checkbox for every variant of its [`Choice`].
+///
+/// Sets get it as their [`FormRenderable`] form, a `Vec` with `#[form(multiselect)]`.
+pub struct MultiSelect<C: ChoiceSet> {
+    pub group: CheckboxGroup,
+    _set: PhantomData<fn() -> C>,
+}
+
+impl<C: ChoiceSet> Default for MultiSelect<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: ChoiceSet> MultiSelect<C> {
+    pub fn new() -> Self {
+        Self {
+            group: CheckboxGroup::new(C::Choice::LABELS.to_vec()),
+            _set: PhantomData,
+        }
+    }
+}
+
+impl<C: ChoiceSet> Form for MultiSelect<C> {
+    type Output = C;
+
+    fn try_build(&self) -> Result<C, FormErrors> {
+        Ok(C::from_choices(
+            self.group.checked_indices().map(C::Choice::from_index),
+        ))
+    }
+
+    fn set_value(&mut self, value: &C) {
+        self.group.set_checked(value.indices());
+    }
+}
+
+impl<C: ChoiceSet> FormWidget for MultiSelect<C> {
+    fn input(&mut self, input: Input) -> bool {
+        self.group.input(input)
+    }
+
+    fn form_height(&self) -> u16 {
+        1 + self.group.height()
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        self.group.render(area, buf, focused);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.group.checked_indices().next().is_none()
+    }
+
+    fn clear(&mut self) {
+        self.group.set_checked([]);
+    }
+}
+
+impl<T: Choice + Eq + Hash, S: BuildHasher + Default> FormRenderable for HashSet<T, S> {
+    type Form = MultiSelect<Self>;
+
+    fn form() -> Self::Form {
+        MultiSelect::new()
+    }
+}
+
+impl<T: Choice + Ord> FormRenderable for BTreeSet<T> {
+    type Form = MultiSelect<Self>;
+
+    fn form() -> Self::Form {
+        MultiSelect::new()
+    }
+}
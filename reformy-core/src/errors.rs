@@ -0,0 +1,137 @@
+use std::fmt;
+
+use crate::FieldPath;
+
+/// Why a single field couldn't be built.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    pub path: FieldPath,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// All the fields that failed when building a form.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormErrors(Vec<FieldError>);
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An error on the form itself rather than on one of its fields.
+    pub fn single(message: impl Into<String>) -> Self {
+        let mut errors = Self::new();
+        errors.push(FieldPath::root(), message);
+        errors
+    }
+
+    /// An error on the field at `path`, e.g. `FormErrors::at("confirm", "passwords differ")`.
+    pub fn at(path: impl Into<FieldPath>, message: impl Into<String>) -> Self {
+        let mut errors = Self::new();
+        errors.push(path.into(), message);
+        errors
+    }
+
+    pub fn push(&mut self, path: FieldPath, message: impl Into<String>) {
+        self.0.push(FieldError {
+            path,
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldError> {
+        self.0.iter()
+    }
+
+    /// The error of the field at `path`, if it failed.
+    pub fn get(&self, path: &FieldPath) -> Option<&FieldError> {
+        self.0.iter().find(|err| &err.path == path)
+    }
+
+    /// Returns these errors nested under `segment`.
+    pub fn prefixed(self, segment: &str) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|err| FieldError {
+                    path: err.path.prefixed(segment),
+                    message: err.message,
+                })
+                .collect(),
+        )
+    }
+
+    /// Collects the errors of the field `segment`, returning its value if it was built.
+    pub fn field<T>(&mut self, segment: &str, result: Result<T, FormErrors>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(errors) => {
+                self.0.extend(errors.prefixed(segment).0);
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for FormErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, err) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", err)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FormErrors {}
+
+/// A message from a validation function, reported on the form itself.
+impl From<String> for FormErrors {
+    fn from(message: String) -> Self {
+        Self::single(message)
+    }
+}
+
+impl From<&str> for FormErrors {
+    fn from(message: &str) -> Self {
+        Self::single(message)
+    }
+}
+
+impl IntoIterator for FormErrors {
+    type Item = FieldError;
+    type IntoIter = std::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FormErrors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
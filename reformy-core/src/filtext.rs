@@ -0,0 +1,240 @@
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{CursorMove, Input, Key, TextArea};
+
+use crate::{ErrorDisplay, Form, FormErrors, FormWidget};
+
+/// How many rows a [`Filtext`] takes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextRows {
+    /// A single line, Enter is left to the parent form.
+    #[default]
+    Single,
+    /// Multiline text always taking this many rows.
+    Fixed(u16),
+    /// Multiline text growing with its lines up to this many rows.
+    Grow(u16),
+}
+
+impl TextRows {
+    /// The cap of `#[form(multiline)]` without a size.
+    pub const DEFAULT_MAX: u16 = 8;
+
+    pub fn is_multiline(self) -> bool {
+        self != TextRows::Single
+    }
+}
+
+/// A text input whose text is parsed into `T`.
+///
+/// Multiline inputs keep Enter, Up and Down to edit the text, the focus
+/// leaves them with Tab or Ctrl+Up/Ctrl+Down instead. Alt+Up/Alt+Down are
+/// left to the parent form, e.g. to reorder the items of a list.
+pub struct Filtext<T: Default + Display + FromStr> {
+    pub input: TextArea<'static>,
+    pub rows: TextRows,
+    pub validate_input: bool,
+    pub error_display: ErrorDisplay,
+    /// Whether the focus has left the field since it was created.
+    pub touched: bool,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Default + Display + FromStr> Default for Filtext<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Default + Display + FromStr> Filtext<T> {
+    pub fn new() -> Self {
+        let input = T::default().to_string();
+        Self {
+            input: TextArea::from([input]),
+            rows: TextRows::Single,
+            validate_input: false,
+            error_display: ErrorDisplay::default(),
+            touched: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Makes the input multiline.
+    pub fn rows(mut self, rows: TextRows) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    pub fn input(&mut self, input: Input) -> bool {
+        // Tab moves the focus rather than inserting a tab.
+        if input.key == Key::Tab {
+            return false;
+        }
+
+        // Home and End move the cursor, with Ctrl they move the focus.
+        match input.key {
+            Key::Home | Key::End if input.ctrl => return false,
+            Key::Home | Key::End => {
+                self.input.input(input);
+                return true;
+            }
+            _ => {}
+        }
+
+        if self.rows.is_multiline() {
+            match input.key {
+                Key::Up | Key::Down if input.ctrl || input.alt => return false,
+                Key::Up | Key::Down => {
+                    self.input.input(input);
+                    return true;
+                }
+                _ => {}
+            }
+        } else if input.key == Key::Enter || (input.ctrl && input.key == Key::Char('m')) {
+            return false;
+        }
+
+        if self.validate_input {
+            let prev = self.input.lines().to_vec();
+            let val = self.input.input(input);
+            let new = self.value_string();
+            if new.parse::<T>().is_err() && !prev.is_empty() {
+                self.input = TextArea::new(prev);
+                false
+            } else {
+                val
+            }
+        } else {
+            self.input.input(input)
+        }
+    }
+
+    pub fn parse(&self) -> Result<T, T::Err> {
+        T::from_str(&self.value_string())
+    }
+
+    pub fn value(&self) -> Option<T> {
+        self.parse().ok()
+    }
+
+    pub fn value_string(&self) -> String {
+        if self.rows.is_multiline() {
+            self.input.lines().join("\n")
+        } else {
+            self.input.lines().concat()
+        }
+    }
+
+    /// The amount of rows of the text input itself.
+    pub fn text_height(&self) -> u16 {
+        match self.rows {
+            TextRows::Single => 1,
+            TextRows::Fixed(rows) => rows.max(1),
+            TextRows::Grow(max) => (self.input.lines().len() as u16).clamp(1, max.max(1)),
+        }
+    }
+
+    /// Whether errors are shown at the moment according to the [`ErrorDisplay`].
+    pub fn shows_errors(&self) -> bool {
+        match self.error_display {
+            ErrorDisplay::Live => true,
+            ErrorDisplay::Touched => self.touched,
+            ErrorDisplay::Hidden => false,
+        }
+    }
+
+    /// Replaces the text of the input.
+    pub fn set_text(&mut self, text: &str) {
+        self.input.select_all();
+        self.input.insert_str(text);
+        // Longer texts are read from their start.
+        if self.rows.is_multiline() {
+            self.input.move_cursor(CursorMove::Top);
+        }
+    }
+}
+
+impl<T> Form for Filtext<T>
+where
+    T: Default + Display + FromStr<Err: Display>,
+{
+    type Output = T;
+
+    fn try_build(&self) -> Result<T, FormErrors> {
+        self.parse()
+            .map_err(|err| FormErrors::single(err.to_string()))
+    }
+
+    fn set_value(&mut self, value: &T) {
+        self.set_text(&value.to_string());
+    }
+}
+
+impl<T> FormWidget for Filtext<T>
+where
+    T: Default + Display + FromStr<Err: Display>,
+{
+    fn input(&mut self, input: Input) -> bool {
+        Filtext::input(self, input)
+    }
+
+    fn form_height(&self) -> u16 {
+        self.text_height() + self.visible_error().is_some() as u16
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, _focused: bool) {
+        let height = self.text_height().min(area.height);
+        let input_area = Rect { height, ..area };
+        self.input.render(input_area, buf);
+
+        if let Some(error) = self.visible_error() {
+            let red = Style::default().fg(Color::Red);
+            buf.set_style(input_area, red);
+
+            let error_area = Rect {
+                y: area.y.saturating_add(height),
+                height: area.height.saturating_sub(height),
+                ..area
+            };
+            Paragraph::new(error).style(red).render(error_area, buf);
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        true
+    }
+
+    fn visible_error(&self) -> Option<String> {
+        if self.shows_errors() {
+            self.parse().err().map(|err| err.to_string())
+        } else {
+            None
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value_string().is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.set_text("");
+    }
+
+    fn set_placeholder(&mut self, text: &str) {
+        self.input.set_placeholder_text(text);
+    }
+
+    fn blur(&mut self) {
+        self.touched = true;
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.error_display = display;
+    }
+}
@@ -0,0 +1,72 @@
+use bitflags::Flags;
+use ratatui::{buffer::Buffer, layout::Rect};
+use tui_textarea::Input;
+
+use crate::{CheckboxGroup, Form, FormErrors, FormWidget};
+
+/// The form of a `bitflags` type, a checkbox for every named flag.
+///
+/// Used for fields with `#[form(flags)]`, requires the `bitflags` feature.
+pub struct FlagsForm<F: Flags> {
+    pub group: CheckboxGroup,
+    _flags: std::marker::PhantomData<fn() -> F>,
+}
+
+impl<F: Flags> Default for FlagsForm<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Flags> FlagsForm<F> {
+    pub fn new() -> Self {
+        let labels = F::FLAGS.iter().map(|flag| flag.name()).collect();
+        Self {
+            group: CheckboxGroup::new(labels),
+            _flags: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<F: Flags> Form for FlagsForm<F> {
+    type Output = F;
+
+    fn try_build(&self) -> Result<F, FormErrors> {
+        let bits = self
+            .group
+            .checked_indices()
+            .map(|idx| F::FLAGS[idx].value().bits())
+            .fold(F::empty().bits(), |acc, bits| acc | bits);
+        Ok(F::from_bits_retain(bits))
+    }
+
+    fn set_value(&mut self, value: &F) {
+        let checked = (0..F::FLAGS.len()).filter(|&idx| {
+            let flag = F::from_bits_retain(F::FLAGS[idx].value().bits());
+            value.contains(flag)
+        });
+        self.group.set_checked(checked.collect::<Vec<_>>());
+    }
+}
+
+impl<F: Flags> FormWidget for FlagsForm<F> {
+    fn input(&mut self, input: Input) -> bool {
+        self.group.input(input)
+    }
+
+    fn form_height(&self) -> u16 {
+        1 + self.group.height()
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        self.group.render(area, buf, focused);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.group.checked_indices().next().is_none()
+    }
+
+    fn clear(&mut self) {
+        self.group.set_checked([]);
+    }
+}
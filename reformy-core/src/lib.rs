@@ -1,44 +1,316 @@
-use std::{fmt::Display, marker::PhantomData, str::FromStr};
+mod boxed;
+mod checkbox;
+mod choice;
+mod errors;
+mod filtext;
+#[cfg(feature = "bitflags")]
+mod flags;
+mod map;
+mod number;
+mod option;
+mod path;
+#[doc(hidden)]
+pub mod probe;
+mod secret;
+mod selector;
+mod vec;
 
-use tui_textarea::{Input, TextArea};
+pub use boxed::BoxForm;
+pub use checkbox::{Checkbox, CheckboxStyle};
+pub use choice::{CheckboxGroup, Choice, ChoiceSet, MultiSelect};
+pub use errors::{FieldError, FormErrors};
+pub use filtext::{Filtext, TextRows};
+#[cfg(feature = "bitflags")]
+pub use flags::FlagsForm;
+pub use map::MapForm;
+pub use number::{Number, NumberForm};
+pub use option::OptionForm;
+pub use path::FieldPath;
+pub use secret::SecretForm;
+pub use selector::{SelectorStyle, VariantSelector};
 
-pub struct Filtext<T: Default + Display + FromStr> {
-    pub input: TextArea<'static>,
-    pub validate_input: bool,
-    _phantom: PhantomData<T>,
-}
+#[cfg(feature = "bitflags")]
+pub use bitflags;
+pub use vec::VecForm;
+
+use std::{fmt::Display, str::FromStr};
+
+use ratatui::{buffer::Buffer, layout::Rect};
+use tui_textarea::{Input, Key};
+
+/// The interactive part of a [`Form`]: handling keys, moving the focus and
+/// rendering, independent of the value the form builds.
+///
+/// Forms of different types can be stored together as `Vec<Box<dyn FormWidget>>`.
+pub trait FormWidget {
+    /// Handles a key press, returns whether the form consumed it.
+    fn input(&mut self, input: Input) -> bool;
+
+    /// The amount of rows needed to render the form.
+    ///
+    /// A form that isn't [inline](FormWidget::is_inline) is rendered below its
+    /// label, so its height includes one row for that label.
+    fn form_height(&self) -> u16;
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool);
 
-impl<T: Default + Display + FromStr> Filtext<T> {
-    pub fn new() -> Self {
-        let input = T::default().to_string();
-        Self {
-            input: TextArea::from([input]),
-            validate_input: false,
-            _phantom: PhantomData,
+    /// Whether the form is rendered on the same row as its label.
+    fn is_inline(&self) -> bool {
+        false
+    }
+
+    /// The error currently shown on the form itself, `None` if it's valid or the
+    /// error shouldn't be shown yet according to its [`ErrorDisplay`].
+    fn visible_error(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether the form holds no input at all, as opposed to an empty value.
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Removes all input from the form, for forms that can be empty.
+    fn clear(&mut self) {}
+
+    /// Sets the text shown while a text input is empty.
+    fn set_placeholder(&mut self, text: &str) {
+        let _ = text;
+    }
+
+    /// Called when the focus leaves the form.
+    fn blur(&mut self) {}
+
+    /// Moves the focus to the first field, used when Tab enters the form.
+    fn focus_first(&mut self) {}
+
+    /// Moves the focus to the last field, used when Shift+Tab enters the form.
+    fn focus_last(&mut self) {}
+
+    /// The path of the field that has the focus, relative to this form.
+    fn focus_path(&self) -> FieldPath {
+        FieldPath::root()
+    }
+
+    /// Moves the focus to the field at `path`, given as the segments of a
+    /// [`FieldPath`]. As much of the path as exists is focused and an empty path
+    /// focuses the first field. Returns whether the whole path exists.
+    fn set_focus_segments(&mut self, path: &[String]) -> bool {
+        if path.is_empty() {
+            self.focus_first();
+            true
+        } else {
+            false
         }
     }
 
-    pub fn input(&mut self, input: Input) -> bool {
-        if self.validate_input {
-            let prev = self.input.lines().to_vec();
-            let val = self.input.input(input);
-            let new = self.value_string();
-            if new.parse::<T>().is_err() && !prev.is_empty() {
-                self.input = TextArea::new(prev);
-                false
-            } else {
-                val
-            }
+    /// Moves the focus to the field at `path`, e.g. `form.set_focus("address.street")`.
+    /// Returns whether the whole path exists.
+    fn set_focus(&mut self, path: impl Into<FieldPath>) -> bool
+    where
+        Self: Sized,
+    {
+        let path = path.into();
+        self.blur();
+        self.set_focus_segments(path.segments())
+    }
+
+    /// Handles a key press on the outermost form.
+    ///
+    /// On top of [`FormWidget::input`], Ctrl+Home and Ctrl+End focus the first
+    /// and the last field, and with `wrap` Tab past the last field goes back to
+    /// the first one and Shift+Tab before the first field goes to the last one.
+    /// Plain Home and End are left to text inputs to move their cursor.
+    ///
+    /// Shift+Tab is expected as `Key::Tab` with `shift` set, terminals usually
+    /// report it as a separate BackTab key.
+    fn root_input(&mut self, input: Input, wrap: bool) -> bool {
+        if self.input(input.clone()) {
+            return true;
+        }
+
+        let first = match input.key {
+            Key::Home if input.ctrl => true,
+            Key::End if input.ctrl => false,
+            Key::Tab if wrap => !input.shift,
+            _ => return false,
+        };
+
+        self.blur();
+        if first {
+            self.focus_first();
         } else {
-            self.input.input(input)
+            self.focus_last();
         }
+        true
+    }
+
+    /// Sets when the form and all its fields show their errors while editing.
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        let _ = display;
+    }
+}
+
+/// An interactive form that builds a value of type [`Form::Output`].
+///
+/// Forms can be nested in each other and stored as `Box<dyn Form<Output = T>>`.
+pub trait Form: FormWidget {
+    type Output;
+
+    /// Builds the value, or reports every field that doesn't hold a valid value.
+    fn try_build(&self) -> Result<Self::Output, FormErrors>;
+
+    /// Builds the value, `None` if any of the fields doesn't hold a valid value.
+    fn build(&self) -> Option<Self::Output> {
+        self.try_build().ok()
+    }
+
+    /// Fills the form with an existing value, e.g. to edit it.
+    fn set_value(&mut self, value: &Self::Output);
+
+    /// Moves the focus to the first field that fails to build, returns whether
+    /// there was one.
+    fn focus_first_error(&mut self) -> bool {
+        let Err(errors) = self.try_build() else {
+            return false;
+        };
+        let Some(error) = errors.iter().next() else {
+            return false;
+        };
+        self.blur();
+        self.set_focus_segments(error.path.segments());
+        true
+    }
+}
+
+/// When a field shows that its current value is invalid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorDisplay {
+    /// As soon as the value is invalid, updated on every key press.
+    #[default]
+    Live,
+    /// Only after the focus has left the field once.
+    Touched,
+    /// Never, errors are only reported by [`Form::try_build`].
+    Hidden,
+}
+
+impl<F: Form + ?Sized> Form for Box<F> {
+    type Output = F::Output;
+
+    fn try_build(&self) -> Result<Self::Output, FormErrors> {
+        (**self).try_build()
+    }
+
+    fn set_value(&mut self, value: &Self::Output) {
+        (**self).set_value(value)
+    }
+
+    fn focus_first_error(&mut self) -> bool {
+        (**self).focus_first_error()
+    }
+}
+
+impl<F: FormWidget + ?Sized> FormWidget for Box<F> {
+    fn input(&mut self, input: Input) -> bool {
+        (**self).input(input)
+    }
+
+    fn form_height(&self) -> u16 {
+        (**self).form_height()
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        (**self).render(area, buf, focused)
+    }
+
+    fn is_inline(&self) -> bool {
+        (**self).is_inline()
+    }
+
+    fn visible_error(&self) -> Option<String> {
+        (**self).visible_error()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn clear(&mut self) {
+        (**self).clear()
+    }
+
+    fn set_placeholder(&mut self, text: &str) {
+        (**self).set_placeholder(text)
+    }
+
+    fn blur(&mut self) {
+        (**self).blur()
     }
 
-    pub fn value(&self) -> Option<T> {
-        T::from_str(&self.value_string()).ok()
+    fn focus_first(&mut self) {
+        (**self).focus_first()
     }
 
-    pub fn value_string(&self) -> String {
-        self.input.lines().concat()
+    fn focus_last(&mut self) {
+        (**self).focus_last()
+    }
+
+    fn focus_path(&self) -> FieldPath {
+        (**self).focus_path()
+    }
+
+    fn set_focus_segments(&mut self, path: &[String]) -> bool {
+        (**self).set_focus_segments(path)
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        (**self).set_error_display(display)
     }
 }
+
+/// A type that can be edited through a [`Form`], usually implemented with `#[derive(FormRenderable)]`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be edited in a form",
+    note = "add `#[derive(FormRenderable)]` to `{Self}`, or use `reformy_core::impl_form_value!({Self})` to edit it as text"
+)]
+pub trait FormRenderable: Sized {
+    type Form: Form<Output = Self>;
+
+    /// Creates an empty form for this type.
+    fn form() -> Self::Form;
+
+    /// Creates a form filled with `value`.
+    fn form_from(value: &Self) -> Self::Form {
+        let mut form = Self::form();
+        form.set_value(value);
+        form
+    }
+}
+
+/// A type that is edited as a single line of text with a [`Filtext`],
+/// implemented together with [`FormRenderable`] by [`impl_form_value!`].
+pub trait FormValue: Default + Display + FromStr<Err: Display> {}
+
+/// Makes types with `Default`, `Display` and `FromStr` editable as a single
+/// line of text, e.g. `reformy_core::impl_form_value!(Email);`.
+#[macro_export]
+macro_rules! impl_form_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $crate::FormValue for $ty {}
+
+            impl $crate::FormRenderable for $ty {
+                type Form = $crate::Filtext<$ty>;
+
+                fn form() -> Self::Form {
+                    $crate::Filtext::new()
+                }
+            }
+        )*
+    };
+}
+
+impl_form_value!(
+    String, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
@@ -0,0 +1,462 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{ErrorDisplay, FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+
+const KEY_WIDTH: u16 = 16;
+
+/// The form of a map such as a `HashMap<K, V>` or `BTreeMap<K, V>`, with a key
+/// and a value form per entry and an "add" row at the end.
+///
+/// The focus is on the value of the selected entry, or on its key while renaming it.
+/// Keys that the focused form doesn't handle itself:
+/// - Up/Down move to the value of the previous or next entry, or the "add" row.
+/// - Tab/Shift+Tab go through every key and value, then the "add" row.
+/// - Enter on the "add" row appends an entry and starts editing its key.
+/// - Enter on a key goes on to its value.
+/// - Alt+r switches between the key and the value of the selected entry.
+/// - Ctrl+Delete removes the selected entry.
+///
+/// Alt+Up/Alt+Down move the selected entry up or down, before its key or value
+/// gets to handle them. This only changes the order in the form, the built map
+/// orders its entries itself.
+///
+/// Entries with the same key fail to build.
+pub struct MapForm<K: FormRenderable, V: FormRenderable, M> {
+    pub entries: Vec<(K::Form, V::Form)>,
+    /// The selected entry, `entries.len()` when the "add" row is selected.
+    pub selected: usize,
+    /// Whether the key rather than the value of the selected entry has the focus.
+    pub editing_key: bool,
+    error_display: ErrorDisplay,
+    _map: PhantomData<fn() -> M>,
+}
+
+impl<K: FormRenderable, V: FormRenderable, M> Default for MapForm<K, V, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: FormRenderable, V: FormRenderable, M> MapForm<K, V, M> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+            editing_key: false,
+            error_display: ErrorDisplay::default(),
+            _map: PhantomData,
+        }
+    }
+
+    fn new_entry(&self) -> (K::Form, V::Form) {
+        let mut key = K::form();
+        let mut value = V::form();
+        key.set_error_display(self.error_display);
+        value.set_error_display(self.error_display);
+        (key, value)
+    }
+
+    /// Appends a new entry and starts editing its key.
+    pub fn push(&mut self) {
+        self.blur_selected();
+        let entry = self.new_entry();
+        self.entries.push(entry);
+        self.selected = self.entries.len() - 1;
+        self.focus_key();
+    }
+
+    /// Removes the entry at `index`.
+    pub fn remove(&mut self, index: usize) {
+        self.entries.remove(index);
+        self.selected = self.selected.min(self.entries.len());
+        self.editing_key = false;
+    }
+
+    fn blur_selected(&mut self) {
+        if let Some((key, value)) = self.entries.get_mut(self.selected) {
+            if self.editing_key {
+                key.blur();
+            } else {
+                value.blur();
+            }
+        }
+    }
+
+    /// Focuses the key of the selected entry, if an entry is selected.
+    fn focus_key(&mut self) {
+        self.editing_key = self.selected < self.entries.len();
+        if let Some((key, _)) = self.entries.get_mut(self.selected) {
+            key.focus_first();
+        }
+    }
+
+    /// Swaps the selected entry with the one `offset` rows away, if there is one.
+    fn move_selected(&mut self, offset: isize) -> bool {
+        let Some(target) = self.selected.checked_add_signed(offset) else {
+            return false;
+        };
+
+        if self.selected >= self.entries.len() || target >= self.entries.len() {
+            return false;
+        }
+
+        self.entries.swap(self.selected, target);
+        self.selected = target;
+        true
+    }
+
+    fn entry_height((key, value): &(K::Form, V::Form)) -> u16 {
+        if value.is_inline() {
+            key.form_height().max(value.form_height())
+        } else {
+            // The key takes the row the value reserves for its label.
+            key.form_height() + value.form_height().saturating_sub(1)
+        }
+    }
+}
+
+impl<K, V, M> MapForm<K, V, M>
+where
+    K: FormRenderable + PartialEq,
+    V: FormRenderable,
+{
+    /// The indices of the entries whose key is the same as the one of an earlier entry.
+    pub fn duplicate_keys(&self) -> Vec<usize> {
+        let keys: Vec<_> = self.entries.iter().map(|(key, _)| key.build()).collect();
+
+        (0..keys.len())
+            .filter(|&idx| {
+                keys[idx].is_some() && keys[..idx].iter().any(|other| other == &keys[idx])
+            })
+            .collect()
+    }
+}
+
+impl<K, V, M> Form for MapForm<K, V, M>
+where
+    K: FormRenderable + PartialEq,
+    V: FormRenderable,
+    M: FromIterator<(K, V)>,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    type Output = M;
+
+    fn try_build(&self) -> Result<M, FormErrors> {
+        let mut errors = FormErrors::new();
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(idx, (key, value))| {
+                let mut entry_errors = FormErrors::new();
+                let key = entry_errors.field("key", key.try_build());
+                let value = entry_errors.field("value", value.try_build());
+                let entry = key.zip(value).ok_or(entry_errors);
+                errors.field(&idx.to_string(), entry)
+            })
+            .collect();
+
+        for idx in self.duplicate_keys() {
+            let path = FieldPath::root().join(idx.to_string()).join("key");
+            errors.push(path, "duplicate key");
+        }
+
+        if errors.is_empty() {
+            Ok(entries.into_iter().flatten().collect())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn set_value(&mut self, value: &M) {
+        self.entries = value
+            .into_iter()
+            .map(|(key, value)| {
+                let mut entry = self.new_entry();
+                entry.0.set_value(key);
+                entry.1.set_value(value);
+                entry
+            })
+            .collect();
+        self.selected = self.selected.min(self.entries.len());
+        self.editing_key = false;
+    }
+}
+
+impl<K, V, M> FormWidget for MapForm<K, V, M>
+where
+    K: FormRenderable + PartialEq,
+    V: FormRenderable,
+    M: FromIterator<(K, V)>,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    fn input(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Up if input.alt => return self.move_selected(-1),
+            Key::Down if input.alt => return self.move_selected(1),
+            _ => {}
+        }
+
+        let on_entry = self.selected < self.entries.len();
+
+        if on_entry && self.editing_key && input.key == Key::Enter {
+            self.entries[self.selected].0.blur();
+            self.editing_key = false;
+            return true;
+        }
+
+        if let Some((key, value)) = self.entries.get_mut(self.selected) {
+            let handled = if self.editing_key {
+                key.input(input.clone())
+            } else {
+                value.input(input.clone())
+            };
+
+            if handled {
+                return true;
+            }
+        }
+
+        match input.key {
+            Key::Char('r') if input.alt && on_entry => {
+                self.blur();
+                self.editing_key = !self.editing_key;
+                true
+            }
+            Key::Up if self.selected > 0 => {
+                self.blur();
+                self.selected -= 1;
+                self.editing_key = false;
+                self.entries[self.selected].1.focus_last();
+                true
+            }
+            Key::Down if on_entry => {
+                self.blur();
+                self.selected += 1;
+                self.editing_key = false;
+                if let Some((_, value)) = self.entries.get_mut(self.selected) {
+                    value.focus_first();
+                }
+                true
+            }
+            Key::Tab if !input.shift && on_entry => {
+                self.blur();
+                if self.editing_key {
+                    self.editing_key = false;
+                    self.entries[self.selected].1.focus_first();
+                } else {
+                    self.selected += 1;
+                    self.focus_key();
+                }
+                true
+            }
+            Key::Tab if input.shift && on_entry && !self.editing_key => {
+                self.blur();
+                self.editing_key = true;
+                self.entries[self.selected].0.focus_last();
+                true
+            }
+            Key::Tab if input.shift && self.selected > 0 => {
+                self.blur();
+                self.selected -= 1;
+                self.editing_key = false;
+                self.entries[self.selected].1.focus_last();
+                true
+            }
+            Key::Enter if !on_entry => {
+                self.push();
+                true
+            }
+            Key::Delete if input.ctrl && on_entry => {
+                self.remove(self.selected);
+                if let Some((_, value)) = self.entries.get_mut(self.selected) {
+                    value.focus_first();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn form_height(&self) -> u16 {
+        let entries: u16 = self.entries.iter().map(Self::entry_height).sum();
+        entries + 2
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        let duplicates = self.duplicate_keys();
+        let mut y = area.y;
+
+        for (idx, entry @ (key, value)) in self.entries.iter().enumerate() {
+            let height = Self::entry_height(entry);
+            let is_selected = focused && self.selected == idx;
+            let row = Rect {
+                y,
+                height: height.min(area.bottom().saturating_sub(y)),
+                ..area
+            };
+
+            let key_area = Rect {
+                width: KEY_WIDTH.min(row.width),
+                height: key.form_height().min(row.height),
+                ..row
+            };
+            key.render(key_area, buf, is_selected && self.editing_key);
+            if duplicates.contains(&idx) {
+                buf.set_style(
+                    Rect {
+                        height: 1,
+                        ..key_area
+                    },
+                    Style::default().fg(Color::Red),
+                );
+            }
+
+            let value_area = if value.is_inline() {
+                Rect {
+                    x: row.x.saturating_add(KEY_WIDTH + 1),
+                    width: row.width.saturating_sub(KEY_WIDTH + 1),
+                    ..row
+                }
+            } else {
+                Rect {
+                    x: row.x.saturating_add(2),
+                    y: row.y.saturating_add(key_area.height),
+                    width: row.width.saturating_sub(2),
+                    height: row.height.saturating_sub(key_area.height),
+                }
+            };
+            value.render(value_area, buf, is_selected && !self.editing_key);
+
+            y = y.saturating_add(height);
+        }
+
+        if y < area.bottom() {
+            let style = if focused && self.selected == self.entries.len() {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Paragraph::new("[+] add").style(style).render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.selected = 0;
+        self.editing_key = false;
+    }
+
+    fn blur(&mut self) {
+        self.blur_selected();
+    }
+
+    fn focus_first(&mut self) {
+        self.selected = 0;
+        self.focus_key();
+    }
+
+    fn focus_last(&mut self) {
+        self.selected = self.entries.len();
+        self.editing_key = false;
+    }
+
+    fn focus_path(&self) -> FieldPath {
+        match self.entries.get(self.selected) {
+            Some((key, _)) if self.editing_key => key.focus_path().prefixed("key"),
+            Some((_, value)) => value.focus_path().prefixed("value"),
+            None => return FieldPath::root(),
+        }
+        .prefixed(self.selected.to_string())
+    }
+
+    /// Entries are addressed as `idx.key` and `idx.value`, `idx` alone focuses the value.
+    fn set_focus_segments(&mut self, path: &[String]) -> bool {
+        let Some((segment, rest)) = path.split_first() else {
+            self.focus_first();
+            return true;
+        };
+        let Some(idx) = segment
+            .parse::<usize>()
+            .ok()
+            .filter(|&idx| idx < self.entries.len())
+        else {
+            return false;
+        };
+
+        self.selected = idx;
+        let (key, value) = &mut self.entries[idx];
+        match rest.split_first() {
+            Some((part, rest)) if part == "key" => {
+                self.editing_key = true;
+                key.set_focus_segments(rest)
+            }
+            Some((part, rest)) if part == "value" => {
+                self.editing_key = false;
+                value.set_focus_segments(rest)
+            }
+            Some(_) => false,
+            None => {
+                self.editing_key = false;
+                value.focus_first();
+                true
+            }
+        }
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.error_display = display;
+        for (key, value) in &mut self.entries {
+            key.set_error_display(display);
+            value.set_error_display(display);
+        }
+    }
+}
+
+impl<K, V, S> FormRenderable for HashMap<K, V, S>
+where
+    K: FormRenderable + Eq + Hash,
+    V: FormRenderable,
+    S: BuildHasher + Default,
+{
+    type Form = MapForm<K, V, Self>;
+
+    fn form() -> Self::Form {
+        MapForm::new()
+    }
+}
+
+impl<K, V> FormRenderable for BTreeMap<K, V>
+where
+    K: FormRenderable + Ord,
+    V: FormRenderable,
+{
+    type Form = MapForm<K, V, Self>;
+
+    fn form() -> Self::Form {
+        MapForm::new()
+    }
+}
@@ -0,0 +1,277 @@
+use std::{fmt::Display, str::FromStr};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{LineGauge, Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{ErrorDisplay, Filtext, Form, FormErrors, FormWidget};
+
+/// A primitive number that can be edited with a [`NumberForm`].
+pub trait Number: Copy + PartialOrd + Default + Display + FromStr<Err: Display> {
+    /// The step used when the field doesn't set one.
+    const ONE: Self;
+
+    /// Adds `step`, saturating at the bounds of the type.
+    fn step_up(self, step: Self) -> Self;
+
+    /// Subtracts `step`, saturating at the bounds of the type.
+    fn step_down(self, step: Self) -> Self;
+
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_number_int {
+    ($($ty:ty),*) => {
+        $(impl Number for $ty {
+            const ONE: Self = 1;
+
+            fn step_up(self, step: Self) -> Self {
+                self.saturating_add(step)
+            }
+
+            fn step_down(self, step: Self) -> Self {
+                self.saturating_sub(step)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+macro_rules! impl_number_float {
+    ($($ty:ty),*) => {
+        $(impl Number for $ty {
+            const ONE: Self = 1.0;
+
+            fn step_up(self, step: Self) -> Self {
+                self + step
+            }
+
+            fn step_down(self, step: Self) -> Self {
+                self - step
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_number_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_number_float!(f32, f64);
+
+/// The form of a number with `#[form(min = .., max = .., step = ..)]`.
+///
+/// Ctrl+Up and Ctrl+Down change the value by the step, clamped to the range.
+/// With `#[form(slider)]` the value is shown as a gauge and changed with
+/// Left/Right or +/- instead of being typed.
+///
+/// Without a slider the value is typed, so Left/Right move the cursor and
+/// +/- are typed as signs, e.g. of `-5` or `1e+3`. Plain Up/Down move the
+/// focus between fields, hence the Ctrl.
+pub struct NumberForm<T: Number> {
+    pub text: Filtext<T>,
+    pub min: Option<T>,
+    pub max: Option<T>,
+    pub step: T,
+    pub slider: bool,
+}
+
+impl<T: Number> Default for NumberForm<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number> NumberForm<T> {
+    pub fn new() -> Self {
+        Self {
+            text: Filtext::new(),
+            min: None,
+            max: None,
+            step: T::ONE,
+            slider: false,
+        }
+    }
+
+    /// Sets the lowest accepted value, raising the current value to it.
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self.clamp_text();
+        self
+    }
+
+    /// Sets the highest accepted value, lowering the current value to it.
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self.clamp_text();
+        self
+    }
+
+    /// Sets how much Ctrl+Up/Ctrl+Down, and Left/Right or +/- on a slider,
+    /// change the value.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Renders the value as a gauge between `min` and `max`.
+    pub fn slider(mut self) -> Self {
+        self.slider = true;
+        self
+    }
+
+    /// Limits `value` to the range.
+    pub fn clamp(&self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn clamp_text(&mut self) {
+        if let Some(value) = self.text.value() {
+            self.text.set_value(&self.clamp(value));
+        }
+    }
+
+    /// Changes the value by one step up or down, starting from the lowest
+    /// value if the text isn't a number.
+    pub fn increment(&mut self, up: bool) {
+        let value = match self.text.value() {
+            Some(value) if up => value.step_up(self.step),
+            Some(value) => value.step_down(self.step),
+            None => self.min.unwrap_or_default(),
+        };
+        self.text.set_value(&self.clamp(value));
+    }
+
+    /// Why the current value is outside of the range, if it is.
+    fn range_error(&self, value: T) -> Option<String> {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => Some(format!("must be at least {}", min)),
+            (_, Some(max)) if value > max => Some(format!("must be at most {}", max)),
+            _ => None,
+        }
+    }
+
+    /// Where the value is between `min` and `max`, from 0 to 1.
+    fn ratio(&self) -> f64 {
+        let (Some(min), Some(max), Some(value)) = (self.min, self.max, self.text.value()) else {
+            return 0.0;
+        };
+        let span = max.to_f64() - min.to_f64();
+        if span <= 0.0 {
+            return 1.0;
+        }
+        ((value.to_f64() - min.to_f64()) / span).clamp(0.0, 1.0)
+    }
+}
+
+impl<T: Number> Form for NumberForm<T> {
+    type Output = T;
+
+    fn try_build(&self) -> Result<T, FormErrors> {
+        let value = self.text.try_build()?;
+        match self.range_error(value) {
+            Some(error) => Err(FormErrors::single(error)),
+            None => Ok(value),
+        }
+    }
+
+    fn set_value(&mut self, value: &T) {
+        self.text.set_value(value);
+    }
+}
+
+impl<T: Number> FormWidget for NumberForm<T> {
+    fn input(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Up if input.ctrl => self.increment(true),
+            Key::Down if input.ctrl => self.increment(false),
+            Key::Right | Key::Char('+') if self.slider => self.increment(true),
+            Key::Left | Key::Char('-') if self.slider => self.increment(false),
+            _ if self.slider => return false,
+            _ => return self.text.input(input),
+        }
+        true
+    }
+
+    fn form_height(&self) -> u16 {
+        if self.visible_error().is_some() { 2 } else { 1 }
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        let input_area = Rect { height: 1, ..area };
+        if self.slider {
+            let style = if focused {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            LineGauge::default()
+                .ratio(self.ratio())
+                .label(self.text.value_string())
+                .filled_style(style)
+                .render(input_area, buf);
+        } else {
+            self.text.input.render(input_area, buf);
+        }
+
+        if let Some(error) = self.visible_error() {
+            let red = Style::default().fg(Color::Red);
+            buf.set_style(input_area, red);
+
+            let error_area = Rect {
+                y: area.y.saturating_add(1),
+                height: area.height.saturating_sub(1),
+                ..area
+            };
+            Paragraph::new(error).style(red).render(error_area, buf);
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        true
+    }
+
+    fn visible_error(&self) -> Option<String> {
+        if !self.text.shows_errors() {
+            return None;
+        }
+        match self.text.parse() {
+            Ok(value) => self.range_error(value),
+            Err(err) => Some(err.to_string()),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.text.clear();
+    }
+
+    fn set_placeholder(&mut self, text: &str) {
+        self.text.set_placeholder(text);
+    }
+
+    fn blur(&mut self) {
+        self.text.blur();
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.text.set_error_display(display);
+    }
+}
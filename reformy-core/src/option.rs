@@ -0,0 +1,267 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{ErrorDisplay, FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+
+/// The form of an `Option<T>`.
+///
+/// Inline forms such as a [`Filtext`](crate::Filtext) build `None` while they're
+/// empty. Other forms are collapsed behind an "add" row, Enter on that row
+/// expands them and Enter on the "remove" row above them collapses them again.
+pub struct OptionForm<T: FormRenderable> {
+    pub form: T::Form,
+    /// Whether a non-inline form is expanded.
+    pub enabled: bool,
+    /// Whether the focus is on the add/remove row rather than on the form.
+    pub header_focused: bool,
+    error_display: ErrorDisplay,
+    placeholder: Option<String>,
+}
+
+impl<T: FormRenderable> Default for OptionForm<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FormRenderable> OptionForm<T> {
+    pub fn new() -> Self {
+        let mut form = Self {
+            form: T::form(),
+            enabled: false,
+            header_focused: true,
+            error_display: ErrorDisplay::default(),
+            placeholder: None,
+        };
+        form.clear();
+        form
+    }
+
+    /// Whether the form currently builds `Some`.
+    pub fn is_some(&self) -> bool {
+        if self.form.is_inline() {
+            !self.form.is_empty()
+        } else {
+            self.enabled
+        }
+    }
+
+    /// An empty inline form is a valid `None`, so it must not show the error
+    /// its form reports for the missing input.
+    fn sync_error_display(&mut self) {
+        if self.form.is_inline() && self.form.is_empty() {
+            self.form.set_error_display(ErrorDisplay::Hidden);
+        } else {
+            self.form.set_error_display(self.error_display);
+        }
+    }
+
+    fn input_collapsible(&mut self, input: Input) -> bool {
+        if !self.header_focused && self.form.input(input.clone()) {
+            return true;
+        }
+
+        match input.key {
+            Key::Enter if self.header_focused => {
+                self.enabled = !self.enabled;
+                self.header_focused = !self.enabled;
+                true
+            }
+            Key::Down if self.header_focused && self.enabled => {
+                self.header_focused = false;
+                self.form.focus_first();
+                true
+            }
+            Key::Up if !self.header_focused => {
+                self.form.blur();
+                self.header_focused = true;
+                true
+            }
+            Key::Tab if input.shift && !self.header_focused => {
+                self.form.blur();
+                self.header_focused = true;
+                true
+            }
+            Key::Tab if !input.shift && self.header_focused && self.enabled => {
+                self.header_focused = false;
+                self.form.focus_first();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T: FormRenderable> Form for OptionForm<T> {
+    type Output = Option<T>;
+
+    fn try_build(&self) -> Result<Option<T>, FormErrors> {
+        if self.is_some() {
+            self.form.try_build().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn set_value(&mut self, value: &Option<T>) {
+        match value {
+            Some(value) => {
+                self.form.set_value(value);
+                self.enabled = true;
+            }
+            None => {
+                self.form = T::form();
+                self.form.clear();
+                if let Some(placeholder) = &self.placeholder {
+                    self.form.set_placeholder(placeholder);
+                }
+                self.enabled = false;
+                self.header_focused = true;
+            }
+        }
+        self.sync_error_display();
+    }
+}
+
+impl<T: FormRenderable> FormWidget for OptionForm<T> {
+    fn input(&mut self, input: Input) -> bool {
+        if self.form.is_inline() {
+            let handled = self.form.input(input);
+            self.sync_error_display();
+            handled
+        } else {
+            self.input_collapsible(input)
+        }
+    }
+
+    fn form_height(&self) -> u16 {
+        if self.form.is_inline() {
+            self.form.form_height()
+        } else if self.enabled {
+            1 + self.form.form_height()
+        } else {
+            2
+        }
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        if self.form.is_inline() {
+            self.form.render(area, buf, focused);
+            return;
+        }
+
+        let header = if self.enabled {
+            "[-] remove"
+        } else {
+            "[+] add"
+        };
+        let style = if focused && self.header_focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        Paragraph::new(header)
+            .style(style)
+            .render(Rect { height: 1, ..area }, buf);
+
+        if self.enabled {
+            let form_area = Rect {
+                y: area.y.saturating_add(1),
+                height: area.height.saturating_sub(1),
+                ..area
+            };
+            self.form
+                .render(form_area, buf, focused && !self.header_focused);
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        self.form.is_inline()
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.is_some()
+    }
+
+    fn clear(&mut self) {
+        self.set_value(&None);
+    }
+
+    fn set_placeholder(&mut self, text: &str) {
+        self.placeholder = Some(text.to_string());
+        self.form.set_placeholder(text);
+    }
+
+    fn visible_error(&self) -> Option<String> {
+        if self.is_some() {
+            self.form.visible_error()
+        } else {
+            None
+        }
+    }
+
+    fn blur(&mut self) {
+        self.form.blur();
+    }
+
+    fn focus_first(&mut self) {
+        if self.form.is_inline() {
+            self.form.focus_first();
+        } else {
+            self.header_focused = true;
+        }
+    }
+
+    fn focus_path(&self) -> FieldPath {
+        if self.form.is_inline() || !self.header_focused {
+            self.form.focus_path()
+        } else {
+            FieldPath::root()
+        }
+    }
+
+    /// The inner form has the same paths as the option, the empty path
+    /// focuses the add/remove row.
+    fn set_focus_segments(&mut self, path: &[String]) -> bool {
+        if self.form.is_inline() {
+            return self.form.set_focus_segments(path);
+        }
+
+        if path.is_empty() || !self.enabled {
+            self.header_focused = true;
+            return path.is_empty();
+        }
+
+        self.header_focused = false;
+        self.form.set_focus_segments(path)
+    }
+
+    fn focus_last(&mut self) {
+        if self.form.is_inline() {
+            self.form.focus_last();
+        } else if self.enabled {
+            self.header_focused = false;
+            self.form.focus_last();
+        } else {
+            self.header_focused = true;
+        }
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.error_display = display;
+        self.sync_error_display();
+    }
+}
+
+impl<T: FormRenderable> FormRenderable for Option<T> {
+    type Form = OptionForm<T>;
+
+    fn form() -> Self::Form {
+        OptionForm::new()
+    }
+}
@@ -0,0 +1,67 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// The location of a field inside a form, like `address.whatever.foo`.
+///
+/// Positional fields are addressed by their index and the fields of an enum
+/// variant are prefixed with the variant name, e.g. `role.Guest.name`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FieldPath(Vec<String>);
+
+impl FieldPath {
+    /// The path of the form itself.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn segments(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns this path nested under `segment`.
+    pub fn prefixed(mut self, segment: impl Into<String>) -> Self {
+        self.0.insert(0, segment.into());
+        self
+    }
+
+    /// Returns the path of `segment` inside this path.
+    pub fn join(mut self, segment: impl Into<String>) -> Self {
+        self.0.push(segment.into());
+        self
+    }
+
+    /// Returns the path of `path` inside this path, e.g. to chain the `Field`
+    /// enums of nested forms.
+    pub fn append(mut self, path: impl Into<FieldPath>) -> Self {
+        self.0.extend(path.into().0);
+        self
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.join("."))
+    }
+}
+
+impl FromStr for FieldPath {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl From<&str> for FieldPath {
+    fn from(s: &str) -> Self {
+        Self(
+            s.split('.')
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+}
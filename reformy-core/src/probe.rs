@@ -0,0 +1,43 @@
+//! Support code for the derive macro, not part of the public API.
+//!
+//! Derived forms start out from the type's [`Default`] value if it has one.
+//! Stable Rust can't ask "does `T: Default` hold" directly, so this uses
+//! method resolution instead: `(&DefaultProbe::<T>::new()).default_value()`
+//! picks [`ViaDefault`] when `T: Default` and falls back to [`NoDefault`]
+//! through an extra auto-ref otherwise. Both traits must be in scope.
+
+use std::marker::PhantomData;
+
+pub struct DefaultProbe<T>(PhantomData<fn() -> T>);
+
+impl<T> DefaultProbe<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for DefaultProbe<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ViaDefault<T> {
+    fn default_value(&self) -> Option<T>;
+}
+
+impl<T: Default> ViaDefault<T> for DefaultProbe<T> {
+    fn default_value(&self) -> Option<T> {
+        Some(T::default())
+    }
+}
+
+pub trait NoDefault<T> {
+    fn default_value(&self) -> Option<T>;
+}
+
+impl<T> NoDefault<T> for &DefaultProbe<T> {
+    fn default_value(&self) -> Option<T> {
+        None
+    }
+}
@@ -0,0 +1,211 @@
+use std::{fmt, fmt::Display, str::FromStr};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{ErrorDisplay, Filtext, Form, FormErrors, FormWidget};
+
+/// The character shown instead of each character of a hidden secret.
+pub const MASK_CHAR: char = '•';
+
+/// The form of a `#[form(secret)]` field, a [`Filtext`] that masks its text.
+///
+/// Alt+s reveals or hides the text. The text is never part of the [`Debug`]
+/// output nor of the errors, and with the `zeroize` feature it's overwritten
+/// when the form is dropped and the text cut with Ctrl+K or Ctrl+W is
+/// discarded. The undo history is disabled so that no copies of the text are
+/// kept around, and the text is only parsed when it changes.
+///
+/// This limits the copies but doesn't prevent them: the value returned by
+/// [`Form::try_build`] and the values parsed to check the text are plain
+/// `T`s that are dropped without being overwritten, as are the cut text and
+/// the buffers the text input reallocates while the text grows.
+pub struct SecretForm<T: Default + Display + FromStr> {
+    pub text: Filtext<T>,
+    revealed: bool,
+    /// Whether the text fails to parse, kept to not parse it on every render.
+    invalid: bool,
+}
+
+impl<T> Default for SecretForm<T>
+where
+    T: Default + Display + FromStr<Err: Display>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SecretForm<T>
+where
+    T: Default + Display + FromStr<Err: Display>,
+{
+    pub fn new() -> Self {
+        let mut text = Filtext::new();
+        text.input.set_mask_char(MASK_CHAR);
+        text.input.set_max_histories(0);
+        let mut form = Self {
+            text,
+            revealed: false,
+            invalid: false,
+        };
+        form.check();
+        form
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.revealed
+    }
+
+    /// Shows the text in plain or masks it again.
+    pub fn toggle_reveal(&mut self) {
+        self.revealed = !self.revealed;
+        if self.revealed {
+            self.text.input.clear_mask_char();
+        } else {
+            self.text.input.set_mask_char(MASK_CHAR);
+        }
+    }
+
+    /// Calls `f` with the text, without copying it when it's a single line.
+    /// Otherwise the joined copy is overwritten afterwards with `zeroize`.
+    fn with_text<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        match self.text.input.lines() {
+            [line] => f(line),
+            lines => {
+                #[cfg(feature = "zeroize")]
+                let text = zeroize::Zeroizing::new(lines.concat());
+                #[cfg(not(feature = "zeroize"))]
+                let text = lines.concat();
+                f(&text)
+            }
+        }
+    }
+
+    fn parse(&self) -> Result<T, FormErrors> {
+        self.with_text(|text| text.parse())
+            .map_err(|_| FormErrors::single("invalid value"))
+    }
+
+    /// Updates whether the text is valid after it changed.
+    fn check(&mut self) {
+        self.invalid = self.parse().is_err();
+    }
+}
+
+impl<T> Form for SecretForm<T>
+where
+    T: Default + Display + FromStr<Err: Display>,
+{
+    type Output = T;
+
+    fn try_build(&self) -> Result<T, FormErrors> {
+        self.parse()
+    }
+
+    fn set_value(&mut self, value: &T) {
+        self.text.set_value(value);
+        self.check();
+    }
+}
+
+impl<T> FormWidget for SecretForm<T>
+where
+    T: Default + Display + FromStr<Err: Display>,
+{
+    fn input(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Char('s') if input.alt => {
+                self.toggle_reveal();
+                true
+            }
+            _ => {
+                let handled = self.text.input(input);
+                self.check();
+                handled
+            }
+        }
+    }
+
+    fn form_height(&self) -> u16 {
+        if self.visible_error().is_some() { 2 } else { 1 }
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, _focused: bool) {
+        let input_area = Rect { height: 1, ..area };
+        self.text.input.render(input_area, buf);
+
+        if let Some(error) = self.visible_error() {
+            let red = Style::default().fg(Color::Red);
+            buf.set_style(input_area, red);
+
+            let error_area = Rect {
+                y: area.y.saturating_add(1),
+                height: area.height.saturating_sub(1),
+                ..area
+            };
+            Paragraph::new(error).style(red).render(error_area, buf);
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        true
+    }
+
+    fn visible_error(&self) -> Option<String> {
+        // The error mustn't repeat the secret itself.
+        if self.invalid && self.text.shows_errors() {
+            Some("invalid value".to_string())
+        } else {
+            None
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.text.input.lines().iter().all(String::is_empty)
+    }
+
+    fn clear(&mut self) {
+        self.text.clear();
+        self.check();
+    }
+
+    fn set_placeholder(&mut self, text: &str) {
+        self.text.set_placeholder(text);
+    }
+
+    fn blur(&mut self) {
+        self.text.blur();
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.text.set_error_display(display);
+    }
+}
+
+impl<T: Default + Display + FromStr> fmt::Debug for SecretForm<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretForm")
+            .field("text", &"<redacted>")
+            .field("revealed", &self.revealed)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: Default + Display + FromStr> Drop for SecretForm<T> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        let mut input = std::mem::take(&mut self.text.input);
+        input.set_yank_text("");
+        for mut line in input.into_lines() {
+            line.zeroize();
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+/// The most variants a [`SelectorStyle::Dropdown`] lists at once.
+const DROPDOWN_ROWS: usize = 8;
+
+/// How the form of an enum lets the user pick a variant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectorStyle {
+    /// A row of all variants, cycled through with Left/Right.
+    #[default]
+    Tabs,
+    /// The selected variant, Enter or typing opens a list of the variants that
+    /// is filtered by the typed text, Enter picks the highlighted one.
+    /// Set with `#[form(dropdown)]`.
+    Dropdown,
+    /// One row per variant, Up/Down move the cursor and Space or Enter picks
+    /// the variant under it. Set with `#[form(radio)]`.
+    Radio,
+}
+
+/// The row(s) of an enum form that select its variant.
+#[derive(Clone, Debug)]
+pub struct VariantSelector {
+    labels: &'static [&'static str],
+    pub selected: usize,
+    pub style: SelectorStyle,
+    /// Whether the dropdown list is open.
+    pub open: bool,
+    /// The text typed into the open dropdown.
+    pub filter: String,
+    /// The variant under the cursor of the radio list or the open dropdown.
+    pub highlighted: usize,
+}
+
+impl VariantSelector {
+    pub fn new(labels: &'static [&'static str], style: SelectorStyle) -> Self {
+        Self {
+            labels,
+            selected: 0,
+            style,
+            open: false,
+            filter: String::new(),
+            highlighted: 0,
+        }
+    }
+
+    pub fn labels(&self) -> &'static [&'static str] {
+        self.labels
+    }
+
+    pub fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.highlighted = index;
+    }
+
+    /// The variants that match the filter of the dropdown.
+    pub fn matches(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        (0..self.labels.len())
+            .filter(|&idx| self.labels[idx].to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    fn open_dropdown(&mut self) {
+        self.open = true;
+        self.filter.clear();
+        self.highlighted = self.selected;
+    }
+
+    /// Keeps the cursor of the dropdown on one of the variants that match the filter.
+    fn refilter(&mut self) {
+        let matches = self.matches();
+        if !matches.contains(&self.highlighted) {
+            self.highlighted = matches.first().copied().unwrap_or(self.selected);
+        }
+    }
+
+    /// Handles a key while the selector has the focus, returns whether it consumed it.
+    pub fn input(&mut self, input: Input) -> bool {
+        match self.style {
+            SelectorStyle::Tabs => self.input_tabs(input),
+            SelectorStyle::Radio => self.input_radio(input),
+            SelectorStyle::Dropdown if self.open => self.input_open_dropdown(input),
+            SelectorStyle::Dropdown => self.input_closed_dropdown(input),
+        }
+    }
+
+    fn input_tabs(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Left if self.selected > 0 => {
+                self.select(self.selected - 1);
+                true
+            }
+            Key::Right if self.selected + 1 < self.labels.len() => {
+                self.select(self.selected + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn input_radio(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Up if self.highlighted > 0 => {
+                self.highlighted -= 1;
+                true
+            }
+            Key::Down if self.highlighted + 1 < self.labels.len() => {
+                self.highlighted += 1;
+                true
+            }
+            Key::Char(' ') | Key::Enter => {
+                self.selected = self.highlighted;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn input_closed_dropdown(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Enter | Key::Char(' ') => {
+                self.open_dropdown();
+                true
+            }
+            Key::Char(c) if !input.ctrl && !input.alt => {
+                self.open_dropdown();
+                self.filter.push(c);
+                self.refilter();
+                true
+            }
+            _ => self.input_tabs(input),
+        }
+    }
+
+    fn input_open_dropdown(&mut self, input: Input) -> bool {
+        let matches = self.matches();
+        let position = matches.iter().position(|&idx| idx == self.highlighted);
+
+        match input.key {
+            Key::Enter => {
+                if position.is_some() {
+                    self.selected = self.highlighted;
+                }
+                self.open = false;
+            }
+            Key::Esc => {
+                self.open = false;
+            }
+            // Tab closes the list and moves on like it would from a closed one.
+            Key::Tab => {
+                self.blur();
+                return false;
+            }
+            Key::Up => {
+                if let Some(pos) = position.filter(|&pos| pos > 0) {
+                    self.highlighted = matches[pos - 1];
+                }
+            }
+            Key::Down => {
+                if let Some(&next) = position.and_then(|pos| matches.get(pos + 1)) {
+                    self.highlighted = next;
+                }
+            }
+            Key::Backspace => {
+                if self.filter.pop().is_none() {
+                    self.open = false;
+                }
+                self.refilter();
+            }
+            Key::Char(c) if !input.ctrl && !input.alt => {
+                self.filter.push(c);
+                self.refilter();
+            }
+            _ => {}
+        }
+
+        // The open list keeps the focus until it's closed or left with Tab.
+        true
+    }
+
+    /// Called when the focus leaves the selector.
+    pub fn blur(&mut self) {
+        self.open = false;
+        self.highlighted = self.selected;
+    }
+
+    /// The amount of rows needed to render the selector.
+    pub fn height(&self) -> u16 {
+        match self.style {
+            SelectorStyle::Tabs => 1,
+            SelectorStyle::Radio => self.labels.len() as u16,
+            SelectorStyle::Dropdown if self.open => {
+                1 + self.matches().len().clamp(1, DROPDOWN_ROWS) as u16
+            }
+            SelectorStyle::Dropdown => 1,
+        }
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        let yellow = Style::default().fg(Color::Yellow);
+
+        match self.style {
+            SelectorStyle::Tabs => {
+                let tabs: Vec<Span> = self
+                    .labels
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, label)| {
+                        if idx != self.selected {
+                            Span::raw(format!(" {} ", label))
+                        } else if focused {
+                            Span::styled(format!("[{}]", label), yellow)
+                        } else {
+                            Span::raw(format!("[{}]", label))
+                        }
+                    })
+                    .collect();
+                Paragraph::new(Line::from(tabs)).render(Rect { height: 1, ..area }, buf);
+            }
+            SelectorStyle::Radio => {
+                for (idx, label) in self.labels.iter().enumerate() {
+                    let Some(y) = area
+                        .y
+                        .checked_add(idx as u16)
+                        .filter(|&y| y < area.bottom())
+                    else {
+                        break;
+                    };
+                    let marker = if idx == self.selected { "(•)" } else { "( )" };
+                    let style = if focused && idx == self.highlighted {
+                        yellow
+                    } else {
+                        Style::default()
+                    };
+                    Paragraph::new(format!("{} {}", marker, label))
+                        .style(style)
+                        .render(
+                            Rect {
+                                y,
+                                height: 1,
+                                ..area
+                            },
+                            buf,
+                        );
+                }
+            }
+            SelectorStyle::Dropdown => {
+                let row = Rect { height: 1, ..area };
+                let style = if focused { yellow } else { Style::default() };
+
+                if !self.open {
+                    Paragraph::new(format!("{} ▾", self.labels[self.selected]))
+                        .style(style)
+                        .render(row, buf);
+                    return;
+                }
+
+                Paragraph::new(format!("{}_", self.filter))
+                    .style(style)
+                    .render(row, buf);
+
+                let matches = self.matches();
+                if matches.is_empty() {
+                    let y = area.y.saturating_add(1);
+                    if y < area.bottom() {
+                        Paragraph::new("  no matches").render(
+                            Rect {
+                                y,
+                                height: 1,
+                                ..area
+                            },
+                            buf,
+                        );
+                    }
+                    return;
+                }
+
+                // Scroll the list so the highlighted variant is visible.
+                let position = matches
+                    .iter()
+                    .position(|&idx| idx == self.highlighted)
+                    .unwrap_or(0);
+                let skip = position.saturating_sub(DROPDOWN_ROWS - 1);
+
+                for (row, &idx) in matches.iter().skip(skip).take(DROPDOWN_ROWS).enumerate() {
+                    let y = area.y.saturating_add(1 + row as u16);
+                    if y >= area.bottom() {
+                        break;
+                    }
+                    let (prefix, style) = if idx == self.highlighted {
+                        ("> ", yellow)
+                    } else {
+                        ("  ", Style::default())
+                    };
+                    Paragraph::new(format!("{}{}", prefix, self.labels[idx]))
+                        .style(style)
+                        .render(
+                            Rect {
+                                y,
+                                height: 1,
+                                ..area
+                            },
+                            buf,
+                        );
+                }
+            }
+        }
+    }
+}
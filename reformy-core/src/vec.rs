@@ -0,0 +1,307 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget},
+};
+use tui_textarea::{Input, Key};
+
+use crate::{ErrorDisplay, FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+
+/// The form of a `Vec<T>`, a list with one form per element and an "add" row
+/// at the end.
+///
+/// Keys that the selected element doesn't handle itself:
+/// - Down/Tab move to the first field of the next element or to the "add" row,
+///   Up/Shift+Tab to the last field of the previous element.
+/// - Enter on the "add" row appends an element.
+/// - Alt+i inserts an element before the selected one.
+/// - Ctrl+Delete removes the selected element.
+///
+/// Alt+Up/Alt+Down move the selected element up or down, before the element
+/// gets to handle them, so they also work while a field inside it is focused.
+pub struct VecForm<T: FormRenderable> {
+    pub items: Vec<T::Form>,
+    /// The selected element, `items.len()` when the "add" row is selected.
+    pub selected: usize,
+    error_display: ErrorDisplay,
+}
+
+impl<T: FormRenderable> Default for VecForm<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FormRenderable> VecForm<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            selected: 0,
+            error_display: ErrorDisplay::default(),
+        }
+    }
+
+    fn new_item(&self) -> T::Form {
+        let mut item = T::form();
+        item.set_error_display(self.error_display);
+        item
+    }
+
+    /// Inserts a new element at `index` and selects it.
+    pub fn insert(&mut self, index: usize) {
+        self.blur();
+        let item = self.new_item();
+        self.items.insert(index, item);
+        self.selected = index;
+        self.items[index].focus_first();
+    }
+
+    /// Removes the element at `index`.
+    pub fn remove(&mut self, index: usize) {
+        self.items.remove(index);
+        self.selected = self.selected.min(self.items.len());
+    }
+
+    /// Swaps the selected element with the one `offset` rows away, if there is one.
+    fn move_selected(&mut self, offset: isize) -> bool {
+        let Some(target) = self.selected.checked_add_signed(offset) else {
+            return false;
+        };
+
+        if self.selected >= self.items.len() || target >= self.items.len() {
+            return false;
+        }
+
+        self.items.swap(self.selected, target);
+        self.selected = target;
+        true
+    }
+}
+
+impl<T: FormRenderable> Form for VecForm<T> {
+    type Output = Vec<T>;
+
+    fn try_build(&self) -> Result<Vec<T>, FormErrors> {
+        let mut errors = FormErrors::new();
+        let values: Vec<_> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| errors.field(&idx.to_string(), item.try_build()))
+            .collect();
+
+        if errors.is_empty() {
+            Ok(values.into_iter().flatten().collect())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn set_value(&mut self, value: &Vec<T>) {
+        self.items = value
+            .iter()
+            .map(|value| {
+                let mut item = self.new_item();
+                item.set_value(value);
+                item
+            })
+            .collect();
+        self.selected = self.selected.min(self.items.len());
+    }
+}
+
+impl<T: FormRenderable> FormWidget for VecForm<T> {
+    fn input(&mut self, input: Input) -> bool {
+        match input.key {
+            Key::Up if input.alt => return self.move_selected(-1),
+            Key::Down if input.alt => return self.move_selected(1),
+            _ => {}
+        }
+
+        if let Some(item) = self.items.get_mut(self.selected)
+            && item.input(input.clone())
+        {
+            return true;
+        }
+
+        let on_item = self.selected < self.items.len();
+
+        match input.key {
+            Key::Up if self.selected > 0 => {
+                self.blur();
+                self.selected -= 1;
+                self.items[self.selected].focus_last();
+                true
+            }
+            Key::Down if on_item => {
+                self.blur();
+                self.selected += 1;
+                if let Some(item) = self.items.get_mut(self.selected) {
+                    item.focus_first();
+                }
+                true
+            }
+            Key::Tab if input.shift && self.selected > 0 => {
+                self.blur();
+                self.selected -= 1;
+                self.items[self.selected].focus_last();
+                true
+            }
+            Key::Tab if !input.shift && on_item => {
+                self.blur();
+                self.selected += 1;
+                if let Some(item) = self.items.get_mut(self.selected) {
+                    item.focus_first();
+                }
+                true
+            }
+            Key::Enter if !on_item => {
+                self.insert(self.items.len());
+                true
+            }
+            Key::Char('i') if input.alt => {
+                self.insert(self.selected);
+                true
+            }
+            Key::Delete if input.ctrl && on_item => {
+                self.remove(self.selected);
+                if let Some(item) = self.items.get_mut(self.selected) {
+                    item.focus_first();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn form_height(&self) -> u16 {
+        let items: u16 = self.items.iter().map(FormWidget::form_height).sum();
+        items + 2
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        let selected_style = Style::default().fg(Color::Yellow);
+        let mut y = area.y;
+
+        for (idx, item) in self.items.iter().enumerate() {
+            let height = item.form_height();
+            let is_selected = focused && self.selected == idx;
+            let style = if is_selected {
+                selected_style
+            } else {
+                Style::default()
+            };
+
+            let row = Rect {
+                y,
+                height: height.min(area.bottom().saturating_sub(y)),
+                ..area
+            };
+
+            if item.is_inline() {
+                let marker = Rect { width: 4, ..row };
+                let item_area = Rect {
+                    x: row.x.saturating_add(4),
+                    width: row.width.saturating_sub(4),
+                    ..row
+                };
+                Paragraph::new(format!("{}.", idx + 1))
+                    .style(style)
+                    .render(marker, buf);
+                item.render(item_area, buf, is_selected);
+            } else {
+                Paragraph::new(format!("#{}:", idx + 1))
+                    .style(style)
+                    .render(Rect { height: 1, ..row }, buf);
+                let item_area = Rect {
+                    x: row.x.saturating_add(2),
+                    y: row.y.saturating_add(1),
+                    width: row.width.saturating_sub(2),
+                    height: row.height.saturating_sub(1),
+                };
+                item.render(item_area, buf, is_selected);
+            }
+
+            y = y.saturating_add(height);
+        }
+
+        if y < area.bottom() {
+            let style = if focused && self.selected == self.items.len() {
+                selected_style
+            } else {
+                Style::default()
+            };
+            Paragraph::new("[+] add").style(style).render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.selected = 0;
+    }
+
+    fn blur(&mut self) {
+        if let Some(item) = self.items.get_mut(self.selected) {
+            item.blur();
+        }
+    }
+
+    fn focus_first(&mut self) {
+        self.selected = 0;
+        if let Some(item) = self.items.first_mut() {
+            item.focus_first();
+        }
+    }
+
+    fn focus_last(&mut self) {
+        self.selected = self.items.len();
+    }
+
+    fn focus_path(&self) -> FieldPath {
+        match self.items.get(self.selected) {
+            Some(item) => item.focus_path().prefixed(self.selected.to_string()),
+            None => FieldPath::root(),
+        }
+    }
+
+    fn set_focus_segments(&mut self, path: &[String]) -> bool {
+        let Some((segment, rest)) = path.split_first() else {
+            self.focus_first();
+            return true;
+        };
+        match segment.parse::<usize>() {
+            Ok(idx) if idx < self.items.len() => {
+                self.selected = idx;
+                self.items[idx].set_focus_segments(rest)
+            }
+            _ => false,
+        }
+    }
+
+    fn set_error_display(&mut self, display: ErrorDisplay) {
+        self.error_display = display;
+        for item in &mut self.items {
+            item.set_error_display(display);
+        }
+    }
+}
+
+impl<T: FormRenderable> FormRenderable for Vec<T> {
+    type Form = VecForm<T>;
+
+    fn form() -> Self::Form {
+        VecForm::new()
+    }
+}
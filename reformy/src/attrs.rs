@@ -0,0 +1,459 @@
+//! Parsing of the `#[form(...)]` attributes.
+
+use proc_macro2::Span;
+use syn::{meta::ParseNestedMeta, spanned::Spanned};
+
+fn unknown(meta: &ParseNestedMeta, place: &str) -> syn::Error {
+    let key = meta
+        .path
+        .get_ident()
+        .map(|ident| ident.to_string())
+        .unwrap_or_else(|| "this".to_string());
+    meta.error(format!("unknown form attribute `{}` on {}", key, place))
+}
+
+fn form_attrs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("form"))
+}
+
+fn string_value(meta: &ParseNestedMeta) -> syn::Result<String> {
+    let value: syn::LitStr = meta.value()?.parse()?;
+    Ok(value.value())
+}
+
+/// The doc comment of a field or variant joined into a single line, used as
+/// its help text when there's no `help = "..."`.
+fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+/// A case convention for the labels of fields and variants, set with
+/// `#[form(rename_all = "...")]`. The serde conventions give the same
+/// names as serde's `rename_all`.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Title,
+    Sentence,
+    Lower,
+    Upper,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Camel,
+    Pascal,
+}
+
+impl RenameRule {
+    const ALL: [(&'static str, RenameRule); 9] = [
+        ("Title Case", RenameRule::Title),
+        ("Sentence case", RenameRule::Sentence),
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+        ("snake_case", RenameRule::Snake),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+        ("kebab-case", RenameRule::Kebab),
+        ("camelCase", RenameRule::Camel),
+        ("PascalCase", RenameRule::Pascal),
+    ];
+
+    /// Renames a `snake_case` field or `PascalCase` variant.
+    pub fn apply(self, ident: &str) -> String {
+        let words = split_words(ident);
+        let capitalized = || {
+            words
+                .iter()
+                .map(|word| capitalize(word))
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            RenameRule::Title => capitalized().join(" "),
+            RenameRule::Sentence => capitalize(&words.join(" ")),
+            // Like serde, these keep the underscores of fields.
+            RenameRule::Lower => ident.trim_start_matches("r#").to_lowercase(),
+            RenameRule::Upper => ident.trim_start_matches("r#").to_uppercase(),
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+            RenameRule::Camel => {
+                let mut out = words.first().cloned().unwrap_or_default();
+                out.extend(capitalized().into_iter().skip(1));
+                out
+            }
+            RenameRule::Pascal => capitalized().concat(),
+        }
+    }
+}
+
+/// The lowercase words of an identifier, split at underscores and at the
+/// start of every uppercase run.
+fn split_words(ident: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let mut prev_lower = false;
+
+    for c in ident.trim_start_matches("r#").chars() {
+        if c == '_' {
+            prev_lower = false;
+            words.push(String::new());
+            continue;
+        }
+        if c.is_uppercase() && prev_lower || words.is_empty() {
+            words.push(String::new());
+        }
+        prev_lower = c.is_lowercase() || c.is_numeric();
+        words.last_mut().unwrap().extend(c.to_lowercase());
+    }
+
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// How the form of an enum selects its variant, tabs unless one of these is given.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Selector {
+    Dropdown,
+    Radio,
+}
+
+impl Selector {
+    pub fn key(self) -> &'static str {
+        match self {
+            Selector::Dropdown => "dropdown",
+            Selector::Radio => "radio",
+        }
+    }
+}
+
+/// The options given through `#[form(...)]` on the struct or enum itself.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub transparent: bool,
+    /// The selector and the attribute that set it, to point errors at it.
+    pub selector: Option<(Selector, syn::Path)>,
+    pub rename_all: Option<RenameRule>,
+    /// Checks the built value as a whole, `fn(&T) -> Result<(), FormErrors>`.
+    pub validate_with: Option<syn::Path>,
+    /// Fills the form from the type's `Default` impl, which the derive can't
+    /// detect on its own for generic types.
+    pub default: bool,
+}
+
+impl ContainerAttrs {
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Self::default();
+
+        for attr in form_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("transparent") {
+                    out.transparent = true;
+                } else if meta.path.is_ident("dropdown") || meta.path.is_ident("radio") {
+                    let selector = if meta.path.is_ident("dropdown") {
+                        Selector::Dropdown
+                    } else {
+                        Selector::Radio
+                    };
+                    if let Some((other, _)) = &out.selector
+                        && *other != selector
+                    {
+                        return Err(meta.error(format!(
+                            "an enum cannot be both `{}` and `{}`",
+                            other.key(),
+                            selector.key()
+                        )));
+                    }
+                    out.selector = Some((selector, meta.path.clone()));
+                } else if meta.path.is_ident("rename_all") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    let rule = RenameRule::ALL
+                        .iter()
+                        .find(|(name, _)| *name == value.value())
+                        .map(|(_, rule)| *rule);
+                    let Some(rule) = rule else {
+                        let names: Vec<_> = RenameRule::ALL
+                            .iter()
+                            .map(|(name, _)| format!("\"{}\"", name))
+                            .collect();
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            format!("unknown case, expected one of {}", names.join(", ")),
+                        ));
+                    };
+                    out.rename_all = Some(rule);
+                } else if meta.path.is_ident("validate_with") {
+                    out.validate_with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") {
+                    out.default = true;
+                } else {
+                    return Err(unknown(&meta, "a struct or enum"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(out)
+    }
+}
+
+/// The options given through `#[form(...)]` on an enum variant.
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub label: Option<String>,
+    /// Shown under the selector while the variant is selected, defaults to the doc comment.
+    pub help: Option<String>,
+}
+
+impl VariantAttrs {
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Self {
+            help: doc_comment(attrs),
+            ..Self::default()
+        };
+
+        for attr in form_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    out.label = Some(string_value(&meta)?);
+                } else if meta.path.is_ident("help") {
+                    out.help = Some(string_value(&meta)?);
+                } else {
+                    return Err(unknown(&meta, "a variant"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(out)
+    }
+}
+
+/// Which form edits a field, a field accepts only one of these.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FieldKind {
+    /// Edit the field with its `FormRenderable` form.
+    #[default]
+    Nested,
+    /// Edit the field as text with a `Filtext`, without requiring `FormValue`.
+    Leaf,
+    /// Edit a `bool` field with a `Checkbox` that shows on/off.
+    Switch,
+    /// Edit a `Vec` of a unit-only enum as a group of checkboxes.
+    Multiselect,
+    /// Edit a `bitflags` type as a group of checkboxes.
+    Flags,
+    /// Edit a number between `min` and `max` with a gauge.
+    Slider,
+    /// Edit a password or token as masked text with a `SecretForm`.
+    Secret,
+    /// Edit text over several rows with a multiline `Filtext`.
+    Multiline,
+}
+
+impl FieldKind {
+    const ALL: [FieldKind; 8] = [
+        FieldKind::Nested,
+        FieldKind::Leaf,
+        FieldKind::Switch,
+        FieldKind::Multiselect,
+        FieldKind::Flags,
+        FieldKind::Slider,
+        FieldKind::Secret,
+        FieldKind::Multiline,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            FieldKind::Nested => "nested",
+            FieldKind::Leaf => "leaf",
+            FieldKind::Switch => "switch",
+            FieldKind::Multiselect => "multiselect",
+            FieldKind::Flags => "flags",
+            FieldKind::Slider => "slider",
+            FieldKind::Secret => "secret",
+            FieldKind::Multiline => "multiline",
+        }
+    }
+}
+
+/// The options given through `#[form(...)]` on a field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// The kind set on the field, `None` to use its `FormRenderable` form.
+    pub kind: Option<FieldKind>,
+    pub label: Option<String>,
+    /// Shown under the field while it has the focus, defaults to the doc comment.
+    pub help: Option<String>,
+    /// Shown in an empty text input.
+    pub placeholder: Option<String>,
+    /// Left out of the form and filled from `default` or `Default` when building.
+    pub skip: bool,
+    /// Shown but not editable nor focusable.
+    pub readonly: bool,
+    /// The initial value of the field, or the value of a skipped field.
+    pub default: Option<syn::Expr>,
+    /// Checks the built value of the field, `fn(&T) -> Result<(), String>`.
+    pub validate: Option<syn::Path>,
+    /// The range and step of a number field.
+    pub min: Option<syn::Expr>,
+    pub max: Option<syn::Expr>,
+    /// Applied with Ctrl+Up/Ctrl+Down, and with Left/Right or +/- on a slider,
+    /// see `NumberForm`.
+    pub step: Option<syn::Expr>,
+    /// The size of a multiline field, from `multiline(rows = ..)` or `multiline(max_rows = ..)`.
+    pub rows: Option<TextRows>,
+}
+
+/// How many rows a multiline field takes.
+#[derive(Clone, Copy)]
+pub enum TextRows {
+    Fixed(u16),
+    Grow(u16),
+}
+
+impl FieldAttrs {
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Self {
+            help: doc_comment(attrs),
+            ..Self::default()
+        };
+        let mut slider_span: Option<Span> = None;
+        let mut range_span: Option<Span> = None;
+
+        for attr in form_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                let kind = FieldKind::ALL
+                    .into_iter()
+                    .find(|kind| meta.path.is_ident(kind.key()));
+
+                if let Some(kind) = kind {
+                    if kind == FieldKind::Slider {
+                        slider_span = Some(meta.path.span());
+                    }
+                    if kind == FieldKind::Multiline && meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|inner| {
+                            let rows: syn::LitInt = inner.value()?.parse()?;
+                            let rows = rows.base10_parse()?;
+                            let rows = if inner.path.is_ident("rows") {
+                                TextRows::Fixed(rows)
+                            } else if inner.path.is_ident("max_rows") {
+                                TextRows::Grow(rows)
+                            } else {
+                                return Err(inner.error("expected `rows` or `max_rows`"));
+                            };
+                            if out.rows.is_some() {
+                                return Err(
+                                    inner.error("`rows` and `max_rows` cannot be used together")
+                                );
+                            }
+                            out.rows = Some(rows);
+                            Ok(())
+                        })?;
+                    }
+                    if let Some(other) = out.kind
+                        && other != kind
+                    {
+                        let (first, second) = (other.min(kind), other.max(kind));
+                        return Err(meta.error(format!(
+                            "a field cannot be both `{}` and `{}`",
+                            first.key(),
+                            second.key()
+                        )));
+                    }
+                    out.kind = Some(kind);
+                } else if meta.path.is_ident("label") {
+                    out.label = Some(string_value(&meta)?);
+                } else if meta.path.is_ident("help") {
+                    out.help = Some(string_value(&meta)?);
+                } else if meta.path.is_ident("placeholder") {
+                    out.placeholder = Some(string_value(&meta)?);
+                } else if meta.path.is_ident("skip") {
+                    out.skip = true;
+                } else if meta.path.is_ident("readonly") {
+                    out.readonly = true;
+                } else if meta.path.is_ident("default") {
+                    out.default = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("validate") {
+                    out.validate = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("min") {
+                    range_span.get_or_insert(meta.path.span());
+                    out.min = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max") {
+                    range_span.get_or_insert(meta.path.span());
+                    out.max = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("step") {
+                    range_span.get_or_insert(meta.path.span());
+                    out.step = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(unknown(&meta, "a field"));
+                }
+                Ok(())
+            })?;
+
+            if out.skip && out.readonly {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "a field cannot be both `skip` and `readonly`",
+                ));
+            }
+        }
+
+        if let (Some(span), Some(kind)) = (range_span, out.kind)
+            && kind != FieldKind::Slider
+        {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "`min`, `max` and `step` cannot be used on a `{}` field",
+                    kind.key()
+                ),
+            ));
+        }
+
+        if let Some(span) = slider_span
+            && (out.min.is_none() || out.max.is_none())
+        {
+            return Err(syn::Error::new(
+                span,
+                "`slider` requires both `min` and `max`",
+            ));
+        }
+
+        Ok(out)
+    }
+
+    /// Whether the field is edited with a `NumberForm`.
+    pub fn is_number(&self) -> bool {
+        self.kind == Some(FieldKind::Slider)
+            || self.min.is_some()
+            || self.max.is_some()
+            || self.step.is_some()
+    }
+}
@@ -1,76 +1,255 @@
+mod attrs;
+
+use attrs::{ContainerAttrs, FieldAttrs, FieldKind, RenameRule, Selector, TextRows, VariantAttrs};
 use proc_macro::TokenStream;
-use quote::{ToTokens, format_ident, quote};
-use syn::{DeriveInput, Field, FieldsNamed, Variant, parse_macro_input, parse_str, parse2};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    DeriveInput, Field, Variant, ext::IdentExt, parse_macro_input, parse2, spanned::Spanned,
+};
 
 #[proc_macro_derive(FormRenderable, attributes(form))]
 pub fn derive_form_renderable(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+
+    match derive(input) {
+        Ok(stream) => stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = input.ident;
+    let vis = input.vis;
+    let attrs = ContainerAttrs::from_attrs(&input.attrs)?;
+
+    if let (syn::Data::Struct(_), Some((selector, path))) = (&input.data, &attrs.selector) {
+        return Err(syn::Error::new_spanned(
+            path,
+            format!("`{}` is only supported on enums", selector.key()),
+        ));
+    }
 
-    let obj = match input.data {
-        syn::Data::Enum(data_enum) => generate_enum_form(&name, data_enum),
-        syn::Data::Struct(data_struct) => generate_struct_form(name, data_struct.fields),
-        _ => {
-            return syn::Error::new_spanned(name, "Only structs and unit enums are supported")
-                .to_compile_error()
-                .into();
+    let selector = attrs.selector.map(|(selector, _)| selector);
+    let rename = attrs.rename_all;
+    let validate_with = attrs.validate_with;
+    let mut obj = match input.data {
+        syn::Data::Enum(data_enum) => {
+            generate_enum_form(&name, &vis, data_enum, selector, rename, validate_with)?
+        }
+        syn::Data::Struct(data_struct) => generate_struct_form(
+            name.clone(),
+            vis,
+            data_struct.fields,
+            attrs.transparent,
+            rename,
+            validate_with,
+        )?,
+        syn::Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "unions are not supported by FormRenderable",
+            ));
         }
     };
 
-    obj.generate().into()
+    let mut generics = input.generics;
+    let params: Vec<syn::Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let bounds: Vec<syn::WherePredicate> = obj
+        .field_bounds()
+        .into_iter()
+        .filter(|bound| mentions_any(bound, &params) || is_plain(bound))
+        .collect();
+    generics.make_where_clause().predicates.extend(bounds);
+
+    if attrs.default {
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let bound = parse2(quote! { #name #ty_generics: ::std::default::Default })?;
+        generics.make_where_clause().predicates.push(bound);
+        obj.use_default();
+    }
+
+    Ok(obj.generate(&generics))
 }
 
-fn extract_named(
-    fields_named: FieldsNamed,
-    name: &syn::Ident,
-    v_ident: &syn::Ident,
-) -> VariantInfo {
-    let mut fields: Vec<Field> = vec![];
+/// Whether `bound` is on a type without generic arguments, so a missing
+/// derive is reported once at the field. Recursive types always go through a
+/// generic type like `Vec<Self>`, so these bounds never require themselves.
+fn is_plain(bound: &syn::WherePredicate) -> bool {
+    match bound {
+        syn::WherePredicate::Type(pred) => match &pred.bounded_ty {
+            syn::Type::Path(path) => {
+                path.qself.is_none() && path.path.segments.iter().all(|s| s.arguments.is_none())
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether `bound` refers to any of the type parameters in `params`.
+fn mentions_any(bound: &syn::WherePredicate, params: &[syn::Ident]) -> bool {
+    fn visit(stream: proc_macro2::TokenStream, params: &[syn::Ident]) -> bool {
+        stream.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(ident) => params.contains(&ident),
+            proc_macro2::TokenTree::Group(group) => visit(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    match bound {
+        syn::WherePredicate::Type(pred) => visit(pred.bounded_ty.to_token_stream(), params),
+        _ => false,
+    }
+}
 
-    for field in fields_named.clone().named {
-        fields.push(field);
+/// The `PhantomData` field and its initializer that keep the type parameters of a
+/// generic form in use, even when some fields of the form don't mention them.
+fn marker_field(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if generics.params.is_empty() {
+        return (quote! {}, quote! {});
     }
 
-    let mystruct = MyStruct::new(name.clone(), Some(v_ident.clone()), fields);
+    let (_, ty_generics, _) = generics.split_for_impl();
+    (
+        quote! { _marker: ::std::marker::PhantomData<fn() -> #name #ty_generics>, },
+        quote! { _marker: ::std::marker::PhantomData, },
+    )
+}
 
-    VariantInfo {
-        v_ident: v_ident.clone(),
-        titles: Some(mystruct),
+/// Fills the freshly created `form` with the type's `Default` value, for types
+/// that implement it. Types without one keep the defaults of their fields.
+///
+/// The probe only sees impls that hold for every instantiation, so a generic
+/// type opts in with `#[form(default)]` and `default` calls its impl directly.
+fn fill_default(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    default: bool,
+) -> proc_macro2::TokenStream {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    if default {
+        return quote! {
+            let mut form = form;
+            ::reformy_core::Form::set_value(
+                &mut form,
+                &<#name #ty_generics as ::std::default::Default>::default(),
+            );
+        };
+    }
+    quote! {
+        let mut form = form;
+        {
+            use ::reformy_core::probe::{NoDefault as _, ViaDefault as _};
+            let probe = ::reformy_core::probe::DefaultProbe::<#name #ty_generics>::new();
+            if let ::core::option::Option::Some(value) = (&probe).default_value() {
+                ::reformy_core::Form::set_value(&mut form, &value);
+            }
+        }
     }
 }
 
-fn extract_variant(name: &syn::Ident, variant: Variant) -> VariantInfo {
-    let v_ident = &variant.ident;
-    match variant.fields {
-        syn::Fields::Unit => VariantInfo {
-            v_ident: v_ident.clone(),
-            titles: None,
+/// Wraps `result`, the expression building the value, to also run the
+/// container's `validate_with` on it.
+fn validated(
+    validate_with: Option<&syn::Path>,
+    result: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match validate_with {
+        Some(validate) => quote! {
+            let result: ::core::result::Result<Self::Output, ::reformy_core::FormErrors> = #result;
+            let value = result?;
+            #validate(&value)?;
+            ::core::result::Result::Ok(value)
         },
-        syn::Fields::Named(fields_named) => extract_named(fields_named, name, v_ident),
-
-        _ => {
-            panic!()
-            /*
-            return syn::Error::new_spanned(&variant.fields, "Only unit or struct variants are supported")
-                .to_compile_error()
-                .into();
-                */
-        }
+        None => result,
     }
 }
 
-fn generate_enum_form(name: &syn::Ident, data_enum: syn::DataEnum) -> MyObject {
+fn extract_variant(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    variant: Variant,
+    rename: Option<RenameRule>,
+) -> syn::Result<VariantInfo> {
+    let attrs = VariantAttrs::from_attrs(&variant.attrs)?;
+
+    let v_ident = &variant.ident;
+    if variant.fields.is_empty() && !matches!(variant.fields, syn::Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            &variant.fields,
+            "variants with empty fields are not supported, use a unit variant instead",
+        ));
+    }
+
+    let label = attrs.label.unwrap_or_else(|| match rename {
+        Some(rule) => rule.apply(&v_ident.to_string()),
+        None => v_ident.to_string(),
+    });
+
+    let (kind, fields): (FieldsKind, Vec<Field>) = match variant.fields {
+        syn::Fields::Unit => {
+            return Ok(VariantInfo {
+                v_ident: v_ident.clone(),
+                label,
+                help: attrs.help,
+                titles: None,
+            });
+        }
+        syn::Fields::Named(fields) => (FieldsKind::Named, fields.named.into_iter().collect()),
+        syn::Fields::Unnamed(fields) => (FieldsKind::Unnamed, fields.unnamed.into_iter().collect()),
+    };
+
+    let mystruct = MyStruct::new(
+        name.clone(),
+        vis.clone(),
+        Some(v_ident.clone()),
+        kind,
+        false,
+        fields,
+        rename,
+    )?;
+
+    Ok(VariantInfo {
+        v_ident: v_ident.clone(),
+        label,
+        help: attrs.help,
+        titles: Some(mystruct),
+    })
+}
+
+fn generate_enum_form(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    data_enum: syn::DataEnum,
+    selector: Option<Selector>,
+    rename: Option<RenameRule>,
+    validate_with: Option<syn::Path>,
+) -> syn::Result<MyObject> {
+    if data_enum.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "enums without variants are not supported by FormRenderable",
+        ));
+    }
+
     let mut fields: Vec<VariantInfo> = vec![];
 
     for variant in data_enum.variants.into_iter() {
-        fields.push(extract_variant(name, variant));
+        fields.push(extract_variant(name, vis, variant, rename)?);
     }
 
     let myenum = MyEnum {
         name: name.clone(),
+        vis: vis.clone(),
         variants: fields,
+        selector,
+        validate_with,
+        default: false,
     };
-    MyObject::Enum(myenum)
+    Ok(MyObject::Enum(myenum))
 }
 
 /// Represents all the info needed to create a Form object
@@ -94,31 +273,55 @@ impl MyObject {
         }
     }
 
-    fn generate(&self) -> proc_macro2::TokenStream {
+    /// Fills the form from `Default` without probing, see [`fill_default`].
+    fn use_default(&mut self) {
+        match self {
+            MyObject::Enum(obj) => obj.default = true,
+            MyObject::Struct(obj) => obj.default = true,
+        }
+    }
+
+    /// The bounds the fields put on the generic parameters of the type.
+    fn field_bounds(&self) -> Vec<syn::WherePredicate> {
+        match self {
+            MyObject::Enum(obj) => obj
+                .variants
+                .iter()
+                .filter_map(|info| info.titles.as_ref())
+                .flat_map(|mys| mys.field_bounds())
+                .collect(),
+            MyObject::Struct(obj) => obj.field_bounds(),
+        }
+    }
+
+    fn generate(&self, generics: &syn::Generics) -> proc_macro2::TokenStream {
         let stream = match self {
-            MyObject::Enum(ob) => ob.generate(),
-            MyObject::Struct(ob) => ob.generate(),
+            MyObject::Enum(ob) => ob.generate(generics),
+            MyObject::Struct(ob) => ob.generate(generics),
         };
 
         let name = self.name();
         let form_name = self.form_name();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let widget: proc_macro2::TokenStream = quote! {
-            impl ratatui::widgets::WidgetRef for #form_name {
+            impl #impl_generics ratatui::widgets::WidgetRef for #form_name #ty_generics #where_clause {
                 fn render_ref(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer) {
                     ratatui::widgets::StatefulWidgetRef::render_ref(self, area, buf, &mut true)
                 }
             }
 
-            impl ratatui::widgets::StatefulWidgetRef for #form_name {
+            impl #impl_generics ratatui::widgets::StatefulWidgetRef for #form_name #ty_generics #where_clause {
                 type State = bool;
                 fn render_ref(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer, state: &mut Self::State) {
-                    self.render(area, buf, *state);
+                    ::reformy_core::FormWidget::render(self, area, buf, *state);
                 }
             }
 
-            impl #name {
-                pub fn form() -> #form_name {
+            impl #impl_generics ::reformy_core::FormRenderable for #name #ty_generics #where_clause {
+                type Form = #form_name #ty_generics;
+
+                fn form() -> Self::Form {
                     #form_name::new()
                 }
             }
@@ -131,7 +334,13 @@ impl MyObject {
 
 struct MyEnum {
     name: syn::Ident,
+    vis: syn::Visibility,
     variants: Vec<VariantInfo>,
+    selector: Option<Selector>,
+    /// Checks the built value, see [`validated`].
+    validate_with: Option<syn::Path>,
+    /// Set by `#[form(default)]`, see [`fill_default`].
+    default: bool,
 }
 
 impl MyEnum {
@@ -143,8 +352,10 @@ impl MyEnum {
         })
     }
 
-    fn generate(&self) -> proc_macro2::TokenStream {
+    fn generate(&self, generics: &syn::Generics) -> proc_macro2::TokenStream {
         let form_name = self.form_name();
+        let vis = &self.vis;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let variant_fields: Vec<_> = self
             .variants
@@ -153,7 +364,10 @@ impl MyEnum {
                 let ident = &info.v_ident;
                 let ty = &info.form_name();
 
-                quote! { pub #ident: #ty  }
+                match &info.titles {
+                    Some(_) => quote! { pub #ident: #ty #ty_generics },
+                    None => quote! { pub #ident: #ty },
+                }
             })
             .collect();
         let form_heights: Vec<_> = self
@@ -182,7 +396,7 @@ impl MyEnum {
 
                 if info.titles.is_some() {
                     quote! {
-                        #idx => self.#ident.input(input.clone()),
+                        #idx => ::reformy_core::FormWidget::input(&mut self.#ident, input.clone()),
                     }
                 } else {
                     quote! {
@@ -198,13 +412,44 @@ impl MyEnum {
             .map(|(idx, info)| {
                 let ident = &info.v_ident;
                 if info.titles.is_some() {
+                    let segment = ident.to_string();
                     quote! {
-                        #idx => self.#ident.build(),
+                        #idx => ::reformy_core::Form::try_build(&self.#ident)
+                            .map_err(|errors| errors.prefixed(#segment)),
                     }
                 } else {
                     let name = &self.name;
                     quote! {
-                        #idx => Some(#name::#ident),
+                        #idx => ::core::result::Result::Ok(#name::#ident),
+                    }
+                }
+            })
+            .collect();
+
+        let try_build = validated(
+            self.validate_with.as_ref(),
+            quote! {
+                match self.selector.selected {
+                    #(#build_matches)*
+                    _ => ::core::result::Result::Err(::reformy_core::FormErrors::single("no variant selected")),
+                }
+            },
+        );
+
+        let set_value_matches: Vec<_> = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, info)| {
+                let ident = &info.v_ident;
+                let name = &self.name;
+                let set_fields = info.titles.as_ref().map(|_| {
+                    quote! { ::reformy_core::Form::set_value(&mut self.#ident, value); }
+                });
+                quote! {
+                    #name::#ident { .. } => {
+                        self.selector.select(#idx);
+                        #set_fields
                     }
                 }
             })
@@ -239,7 +484,7 @@ impl MyEnum {
 
                 if info.titles.is_some() {
                     quote! {
-                        #idx => self.#ident.render(area, buf, state.clone()),
+                        #idx => ::reformy_core::FormWidget::render(&self.#ident, area, buf, state && !self.selector_focused),
                     }
                 } else {
                     quote! {
@@ -248,124 +493,427 @@ impl MyEnum {
                 }
             })
             .collect();
+        let blur_matches: Vec<_> = self
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.titles.is_some())
+            .map(|(idx, info)| {
+                let ident = &info.v_ident;
+                quote! {
+                    #idx => ::reformy_core::FormWidget::blur(&mut self.#ident),
+                }
+            })
+            .collect();
+        let focus_matches: Vec<_> = self
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.titles.is_some())
+            .map(|(idx, info)| {
+                let ident = &info.v_ident;
+                quote! {
+                    #idx => if first {
+                        ::reformy_core::FormWidget::focus_first(&mut self.#ident)
+                    } else {
+                        ::reformy_core::FormWidget::focus_last(&mut self.#ident)
+                    },
+                }
+            })
+            .collect();
+        let path_matches: Vec<_> = self
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.titles.is_some())
+            .map(|(idx, info)| {
+                let ident = &info.v_ident;
+                let segment = ident.to_string();
+                quote! {
+                    #idx => ::reformy_core::FormWidget::focus_path(&self.#ident).prefixed(#segment),
+                }
+            })
+            .collect();
+        // Only the fields of the selected variant can be focused.
+        let set_focus_matches: Vec<_> = self
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.titles.is_some())
+            .map(|(idx, info)| {
+                let ident = &info.v_ident;
+                let segment = ident.to_string();
+                quote! {
+                    (#idx, #segment) => {
+                        self.selector_focused = false;
+                        ::reformy_core::FormWidget::set_focus_segments(&mut self.#ident, rest)
+                    }
+                }
+            })
+            .collect();
+        let field_enum = self.field_enum();
+        let error_display_calls: Vec<_> = self
+            .variants
+            .iter()
+            .filter(|info| info.titles.is_some())
+            .map(|info| {
+                let ident = &info.v_ident;
+                quote! {
+                    ::reformy_core::FormWidget::set_error_display(&mut self.#ident, display);
+                }
+            })
+            .collect();
         let variant_titles: Vec<_> = self
             .variants
             .iter()
             .map(|info| {
                 info.titles
                     .as_ref()
-                    .map(|mys| mys.generate())
+                    .map(|mys| mys.generate(generics))
                     .unwrap_or_default()
             })
             .collect();
-        let variant_display: Vec<_> = self
+        let variant_labels: Vec<_> = self
             .variants
             .iter()
-            .enumerate()
-            .map(|(idx, info)| {
-                let label = info.v_ident.to_string();
-                quote!(#idx => #label,)
+            .map(|info| info.label.clone())
+            .collect();
+        let variant_helps: Vec<_> = self
+            .variants
+            .iter()
+            .map(|info| match &info.help {
+                Some(help) => quote! { ::core::option::Option::Some(#help) },
+                None => quote! { ::core::option::Option::None },
             })
             .collect();
+        let has_fields: Vec<_> = self
+            .variants
+            .iter()
+            .map(|info| info.titles.is_some())
+            .collect();
 
-        let num_variants = variant_display.len();
+        let style = match self.selector {
+            None => quote! { ::reformy_core::SelectorStyle::Tabs },
+            Some(Selector::Dropdown) => quote! { ::reformy_core::SelectorStyle::Dropdown },
+            Some(Selector::Radio) => quote! { ::reformy_core::SelectorStyle::Radio },
+        };
+        let num_variants = variant_labels.len();
         let name = &self.name;
+        let fill_default = fill_default(name, generics, self.default);
+        let choice = self.generate_choice(generics);
 
         quote! {
-        #(#variant_titles)*
+            #(#variant_titles)*
 
-        pub struct #form_name {
-            pub selected_variant: usize,
-            #(#variant_fields,)*
-        }
+            #field_enum
 
-        impl #form_name {
-            pub fn new() -> Self {
-                Self {
-                    selected_variant: 0,
-                    #(#variant_inits,)*
-                }
-            }
-            
-            pub fn form_height(&self) -> u16 {
-                let index = self.selected_variant;
-                (match index {
-                    #(#form_heights)*
-                    _ => 0,
-                } + 2) as u16
-            }
-
-            pub fn input(&mut self, input: tui_textarea::Input) -> bool {
-                let key = input.key.clone();
-                (match self.selected_variant {
-                    #(#input_matches)*
-                    _ => false,
-                } ||
-                match key {
-                    tui_textarea::Key::Left if self.selected_variant > 0 => {
-                        self.selected_variant -= 1;
-                        true
-                    }
-                    tui_textarea::Key::Right if self.selected_variant + 1 < #num_variants => {
-                        self.selected_variant += 1;
-                        true
-                    }
-                    _ => false,
-                })
+            #[allow(non_snake_case)]
+            #vis struct #form_name #impl_generics #where_clause {
+                pub selector: ::reformy_core::VariantSelector,
+                /// Whether the focus is on the row that selects the variant
+                /// rather than on the fields of the selected variant.
+                pub selector_focused: bool,
+                #(#variant_fields,)*
             }
 
-            pub fn build(&self) -> Option<#name> {
-                match self.selected_variant {
-                    #(#build_matches)*
-                    _ => None,
+            impl #impl_generics #form_name #ty_generics #where_clause {
+                pub fn new() -> Self {
+                    let form = Self {
+                        selector: ::reformy_core::VariantSelector::new(&[#(#variant_labels),*], #style),
+                        selector_focused: true,
+                        #(#variant_inits,)*
+                    };
+                    #fill_default
+                    form
+                }
+
+                fn variant_has_fields(&self) -> bool {
+                    [#(#has_fields),*][self.selector.selected]
+                }
+
+                /// Moves the focus inside the selected variant to its first or last field.
+                fn focus_variant(&mut self, first: bool) {
+                    match self.selector.selected {
+                        #(#focus_matches)*
+                        _ => {
+                            let _ = first;
+                        }
+                    }
+                }
+
+                fn variant_help(&self) -> ::core::option::Option<&'static str> {
+                    let helps: [::core::option::Option<&'static str>; #num_variants] = [#(#variant_helps),*];
+                    helps[self.selector.selected]
                 }
             }
 
-            pub fn render(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer, state: bool) {
-                use ratatui::widgets::WidgetRef;
-                use ratatui::prelude::Constraint;
+            impl #impl_generics ::std::default::Default for #form_name #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
 
-                let label = match self.selected_variant {
-                    #(#variant_display)*
-                    _ => "???",
-                };
+            impl #impl_generics ::reformy_core::Form for #form_name #ty_generics #where_clause {
+                type Output = #name #ty_generics;
 
-                let title = if state {
-                    format!(">{}: ", label)
-                } else {
-                    format!("{}: ", label)
-                };
+                fn try_build(&self) -> ::core::result::Result<Self::Output, ::reformy_core::FormErrors> {
+                    #try_build
+                }
 
-                let chunks = ratatui::layout::Layout::default()
-                    .direction(ratatui::layout::Direction::Vertical)
-                    .constraints(vec![Constraint::Length(1), Constraint::Min(0)])
-                    .split(area);
+                fn set_value(&mut self, value: &Self::Output) {
+                    match value {
+                        #(#set_value_matches)*
+                    }
+                }
+            }
 
-                ratatui::widgets::Paragraph::new(format!("[{}]", label)).render_ref(chunks[0], buf);
+            impl #impl_generics ::reformy_core::FormWidget for #form_name #ty_generics #where_clause {
+                fn form_height(&self) -> u16 {
+                    let index = self.selector.selected;
+                    (match index {
+                        #(#form_heights)*
+                        _ => 0,
+                    } + 1) as u16 + self.selector.height() + self.variant_help().is_some() as u16
+                }
 
-                let area = chunks[1];
+                fn input(&mut self, input: tui_textarea::Input) -> bool {
+                    if !self.selector_focused {
+                        let handled = match self.selector.selected {
+                            #(#input_matches)*
+                            _ => false,
+                        };
 
-                let chunks = ratatui::layout::Layout::default()
-                    .direction(ratatui::layout::Direction::Horizontal)
-                    .constraints(vec![Constraint::Length(2), Constraint::Min(0)])
-                    .split(area);
+                        if handled {
+                            return true;
+                        }
 
-                let area = chunks[1];
+                        return match input.key {
+                            tui_textarea::Key::Up if !input.alt => {
+                                ::reformy_core::FormWidget::blur(self);
+                                self.selector_focused = true;
+                                true
+                            }
+                            tui_textarea::Key::Tab if input.shift => {
+                                ::reformy_core::FormWidget::blur(self);
+                                self.selector_focused = true;
+                                true
+                            }
+                            _ => false,
+                        };
+                    }
 
-                match self.selected_variant {
-                    #(#render_matches)*
-                    _ => {}
-                };
+                    if self.selector.input(input.clone()) {
+                        return true;
+                    }
+
+                    match input.key {
+                        tui_textarea::Key::Down if !input.alt && self.variant_has_fields() => {
+                            self.selector.blur();
+                            self.selector_focused = false;
+                            self.focus_variant(true);
+                            true
+                        }
+                        tui_textarea::Key::Tab if !input.shift && self.variant_has_fields() => {
+                            self.selector.blur();
+                            self.selector_focused = false;
+                            self.focus_variant(true);
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+
+                fn focus_first(&mut self) {
+                    self.selector_focused = true;
+                }
+
+                fn focus_path(&self) -> ::reformy_core::FieldPath {
+                    if self.selector_focused {
+                        return ::reformy_core::FieldPath::root();
+                    }
+                    match self.selector.selected {
+                        #(#path_matches)*
+                        _ => ::reformy_core::FieldPath::root(),
+                    }
+                }
+
+                /// The fields of a variant are prefixed with its name, the empty
+                /// path focuses the selector.
+                fn set_focus_segments(&mut self, path: &[::std::string::String]) -> bool {
+                    let ::core::option::Option::Some((segment, rest)) = path.split_first() else {
+                        self.selector_focused = true;
+                        return true;
+                    };
+                    match (self.selector.selected, segment.as_str()) {
+                        #(#set_focus_matches)*
+                        _ => {
+                            self.selector_focused = true;
+                            false
+                        }
+                    }
+                }
+
+                fn focus_last(&mut self) {
+                    if self.variant_has_fields() {
+                        self.selector_focused = false;
+                        self.focus_variant(false);
+                    } else {
+                        self.selector_focused = true;
+                    }
+                }
+
+                fn blur(&mut self) {
+                    self.selector.blur();
+                    match self.selector.selected {
+                        #(#blur_matches)*
+                        _ => {}
+                    }
+                }
+
+                fn set_error_display(&mut self, display: ::reformy_core::ErrorDisplay) {
+                    let _ = display;
+                    #(#error_display_calls)*
+                }
+
+                fn render(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer, state: bool) {
+                    use ratatui::prelude::Constraint;
+
+                    let help = self.variant_help();
+                    let chunks = ratatui::layout::Layout::default()
+                        .direction(ratatui::layout::Direction::Vertical)
+                        .constraints(vec![
+                            Constraint::Length(self.selector.height()),
+                            Constraint::Length(help.is_some() as u16),
+                            Constraint::Min(0),
+                        ])
+                        .split(area);
+
+                    self.selector.render(chunks[0], buf, state && self.selector_focused);
+
+                    if let ::core::option::Option::Some(help) = help {
+                        ratatui::widgets::WidgetRef::render_ref(
+                            &ratatui::widgets::Paragraph::new(help)
+                                .style(ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray)),
+                            chunks[1],
+                            buf,
+                        );
+                    }
+
+                    let area = chunks[2];
+
+                    let chunks = ratatui::layout::Layout::default()
+                        .direction(ratatui::layout::Direction::Horizontal)
+                        .constraints(vec![Constraint::Length(2), Constraint::Min(0)])
+                        .split(area);
+
+                    let area = chunks[1];
+
+                    match self.selector.selected {
+                        #(#render_matches)*
+                        _ => {}
+                    };
+                }
             }
+
+            #choice
+
+        }
+    }
+}
+
+impl MyEnum {
+    /// Implements `Choice` for enums that only have unit variants, so that
+    /// sets of them can be edited as a group of checkboxes.
+    fn generate_choice(&self, generics: &syn::Generics) -> proc_macro2::TokenStream {
+        if self.variants.iter().any(|info| info.titles.is_some()) {
+            return quote! {};
+        }
+
+        let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let idents: Vec<_> = self.variants.iter().map(|info| &info.v_ident).collect();
+        let labels = self.variants.iter().map(|info| &info.label);
+        let indices: Vec<_> = (0..idents.len()).collect();
+
+        quote! {
+            impl #impl_generics ::reformy_core::Choice for #name #ty_generics #where_clause {
+                const LABELS: &'static [&'static str] = &[#(#labels),*];
+
+                fn from_index(index: usize) -> Self {
+                    match index {
+                        #(#indices => #name::#idents,)*
+                        _ => panic!("variant index {} out of range", index),
+                    }
+                }
+
+                fn index(&self) -> usize {
+                    match self {
+                        #(#name::#idents => #indices,)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the enum with a variant per variant of the enum that has
+    /// fields, which turns into the `FieldPath` prefix of those fields.
+    fn field_enum(&self) -> proc_macro2::TokenStream {
+        let variants: Vec<_> = self
+            .variants
+            .iter()
+            .filter(|info| info.titles.is_some())
+            .map(|info| &info.v_ident)
+            .collect();
+        if variants.is_empty() {
+            return quote! {};
         }
 
-    }.into()
+        let name = format_ident!("{}Field", self.name);
+        let vis = &self.vis;
+        let segments: Vec<_> = variants.iter().map(|ident| ident.to_string()).collect();
+        let docs = segments
+            .iter()
+            .map(|segment| format!("The fields of the `{}` variant.", segment));
+        let doc = format!(
+            "The variants of [`{}Form`] with fields, usable as a `FieldPath`.",
+            self.name
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #vis enum #name {
+                #(
+                    #[doc = #docs]
+                    #variants,
+                )*
+            }
+
+            impl #name {
+                /// The segment of the variant in a `FieldPath`.
+                pub fn segment(self) -> &'static str {
+                    match self {
+                        #(#name::#variants => #segments,)*
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#name> for ::reformy_core::FieldPath {
+                fn from(field: #name) -> Self {
+                    ::reformy_core::FieldPath::root().join(field.segment())
+                }
+            }
+        }
     }
 }
 
 /// A single variant in an enum
 struct VariantInfo {
     v_ident: syn::Ident,
+    /// The name shown in the variant selector.
+    label: String,
+    help: Option<String>,
     /// The fields if it's a data enum, none if it's unit
     titles: Option<MyStruct>,
 }
@@ -374,7 +922,7 @@ impl VariantInfo {
     fn form_name(&self) -> syn::Type {
         match &self.titles {
             Some(s) => s.form_name(),
-            None => parse_str("()").unwrap(),
+            None => parse2(quote! { () }).unwrap(),
         }
     }
 }
@@ -382,56 +930,265 @@ impl VariantInfo {
 #[derive(Clone, Debug)]
 struct FieldType {
     ty: syn::Type,
-    is_leaf: bool,
+    /// Expression creating an empty form of type `ty`.
+    init: proc_macro2::TokenStream,
 }
 
 /// A single field in a struct-like object.
 struct StructField {
+    /// The name of the field on the generated form, `_0`, `_1`... for positional fields.
     field: syn::Ident,
+    /// The name of the field in a `FieldPath`, the index for positional fields.
+    segment: String,
+    /// The position of the field in the struct.
+    index: usize,
+    label: String,
+    /// Shown under the field while it's selected.
+    help: Option<String>,
+    /// Rendered but never focused.
+    readonly: bool,
+    /// The value the form is filled with when created.
+    default: Option<syn::Expr>,
     field_ty: FieldType,
+    /// What the field's type has to implement for its form to exist.
+    bound: syn::WherePredicate,
+    /// Expression building the field's value from its form, with its `validate`.
+    try_build: proc_macro2::TokenStream,
     build: proc_macro2::TokenStream,
     render: proc_macro2::TokenStream,
 }
 
+/// A field left out of the form with `#[form(skip)]`.
+struct SkippedField {
+    /// The position of the field in the struct.
+    index: usize,
+    /// The field initializer used when building.
+    build: proc_macro2::TokenStream,
+    /// `Default` bound of the field's type, `None` when it has a default expression.
+    bound: Option<syn::WherePredicate>,
+}
+
+/// Whether a struct-like object has named fields or positional ones.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldsKind {
+    Named,
+    Unnamed,
+}
+
 struct MyStruct {
     name: syn::Ident,
+    vis: syn::Visibility,
     variant: Option<syn::Ident>,
+    kind: FieldsKind,
+    /// A single-field struct whose form is just the form of that field.
+    transparent: bool,
     fields: Vec<StructField>,
+    skipped: Vec<SkippedField>,
+    /// Checks the built value, see [`validated`].
+    validate_with: Option<syn::Path>,
+    /// Set by `#[form(default)]`, see [`fill_default`].
+    default: bool,
 }
 
 impl MyStruct {
-    fn new(name: syn::Ident, variant: Option<syn::Ident>, fields: Vec<Field>) -> Self {
+    fn new(
+        name: syn::Ident,
+        vis: syn::Visibility,
+        variant: Option<syn::Ident>,
+        kind: FieldsKind,
+        transparent: bool,
+        fields: Vec<Field>,
+        rename: Option<RenameRule>,
+    ) -> syn::Result<Self> {
         let mut xfields: Vec<StructField> = vec![];
+        let mut skipped: Vec<SkippedField> = vec![];
 
         for (idx, field) in fields.iter().enumerate() {
-            xfields.push(extract_field(idx, field));
+            let attrs = FieldAttrs::from_attrs(&field.attrs)?;
+            if attrs.skip {
+                skipped.push(skip_field(idx, field, attrs.default)?);
+            } else {
+                xfields.push(extract_field(idx, xfields.len(), field, attrs, rename)?);
+            }
         }
 
-        Self {
+        if xfields.is_empty() {
+            let span = match &variant {
+                Some(variant) => variant.span(),
+                None => name.span(),
+            };
+            return Err(syn::Error::new(
+                span,
+                "at least one field must not be `#[form(skip)]`",
+            ));
+        }
+
+        Ok(Self {
             name,
+            vis,
             variant,
+            kind,
+            transparent,
             fields: xfields,
-        }
+            skipped,
+            validate_with: None,
+            default: false,
+        })
     }
 
     fn height_exprs(&self, is_enum: bool) -> Vec<proc_macro2::TokenStream> {
+        let form = match &self.variant {
+            Some(var) if is_enum => quote! { self.#var },
+            _ => quote! { self },
+        };
+
         self.fields
             .iter()
-            .map(|f| {
-                if f.field_ty.is_leaf {
-                    quote! { 1 }
-                } else {
-                    //let height = quote! { self.#ident.form_height() };
-                    let ident = f.field.clone();
-                    match &self.variant {
-                        Some(var) if is_enum => quote! {self.#var.#ident.form_height()},
-                        _ => quote! {self.#ident.form_height()},
-                    }
-                }
+            .enumerate()
+            .map(|(idx, f)| {
+                let ident = &f.field;
+                let help = f
+                    .help
+                    .as_ref()
+                    .map(|_| quote! { + (#form.focused && #form.selected == #idx) as u16 });
+                quote! { ::reformy_core::FormWidget::form_height(&#form.#ident) #help }
             })
             .collect()
     }
 
+    /// The width of the label column of inline fields.
+    fn label_width(&self) -> u16 {
+        self.fields
+            .iter()
+            .map(|f| f.label.chars().count() as u16 + 3)
+            .max()
+            .unwrap_or(0)
+            .max(12)
+    }
+
+    /// The pattern that binds every field of `path` to the ident of its form,
+    /// ignoring skipped fields.
+    fn pattern(&self, path: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.kind {
+            FieldsKind::Named => {
+                let idents = self.fields.iter().map(|f| &f.field);
+                let rest = (!self.skipped.is_empty()).then(|| quote! { .. });
+                quote! { #path { #(#idents,)* #rest } }
+            }
+            FieldsKind::Unnamed => {
+                let mut members: Vec<_> = self
+                    .fields
+                    .iter()
+                    .map(|f| (f.index, f.field.to_token_stream()))
+                    .chain(self.skipped.iter().map(|s| (s.index, quote! { _ })))
+                    .collect();
+                members.sort_by_key(|(index, _)| *index);
+                let members = members.into_iter().map(|(_, member)| member);
+                quote! { #path ( #(#members,)* ) }
+            }
+        }
+    }
+
+    /// The initializers of every field of the built value, in declaration order.
+    fn builds(&self) -> Vec<proc_macro2::TokenStream> {
+        let mut builds: Vec<_> = self
+            .fields
+            .iter()
+            .map(|f| (f.index, f.build.clone()))
+            .chain(self.skipped.iter().map(|s| (s.index, s.build.clone())))
+            .collect();
+        builds.sort_by_key(|(index, _)| *index);
+        builds.into_iter().map(|(_, build)| build).collect()
+    }
+
+    /// Fills the fields that have a `#[form(default = ...)]` in `new()`.
+    fn field_defaults(&self) -> proc_macro2::TokenStream {
+        let defaults: Vec<_> = self
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let ident = &f.field;
+                f.default.as_ref().map(|default| {
+                    quote! { ::reformy_core::Form::set_value(&mut form.#ident, &(#default)); }
+                })
+            })
+            .collect();
+
+        if defaults.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                let mut form = form;
+                #(#defaults)*
+            }
+        }
+    }
+
+    /// The name of the enum listing the fields of the form, e.g. `UserField`.
+    fn field_enum_name(&self) -> syn::Ident {
+        match &self.variant {
+            Some(var) => format_ident!("{}{}Field", self.name, var),
+            None => format_ident!("{}Field", self.name),
+        }
+    }
+
+    /// Generates the enum with a variant per field of the form, which turns
+    /// into the `FieldPath` of that field.
+    fn field_enum(&self) -> proc_macro2::TokenStream {
+        let name = self.field_enum_name();
+        let vis = &self.vis;
+        let variants: Vec<_> = self
+            .fields
+            .iter()
+            .map(|f| match self.kind {
+                FieldsKind::Named => {
+                    format_ident!(
+                        "{}",
+                        RenameRule::Pascal.apply(&f.segment),
+                        span = f.field.span()
+                    )
+                }
+                FieldsKind::Unnamed => format_ident!("Field{}", f.segment),
+            })
+            .collect();
+        let segments: Vec<_> = self.fields.iter().map(|f| &f.segment).collect();
+        let docs = segments
+            .iter()
+            .map(|segment| format!("The `{}` field.", segment));
+        let form_name = self.form_name();
+        let doc = format!(
+            "The fields of [`{}`], usable as a `FieldPath`.",
+            quote! { #form_name }
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #vis enum #name {
+                #(
+                    #[doc = #docs]
+                    #variants,
+                )*
+            }
+
+            impl #name {
+                /// The segment of the field in a `FieldPath`.
+                pub fn segment(self) -> &'static str {
+                    match self {
+                        #(#name::#variants => #segments,)*
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#name> for ::reformy_core::FieldPath {
+                fn from(field: #name) -> Self {
+                    ::reformy_core::FieldPath::root().join(field.segment())
+                }
+            }
+        }
+    }
+
     fn height(&self, is_enum: bool) -> proc_macro2::TokenStream {
         let heights = self.height_exprs(is_enum);
         quote! {
@@ -450,9 +1207,17 @@ impl MyStruct {
         })
     }
 
-    fn generate(&self) -> proc_macro2::TokenStream {
-        if self.fields.is_empty() {
-            return quote! {}.into();
+    fn field_bounds(&self) -> Vec<syn::WherePredicate> {
+        self.fields
+            .iter()
+            .map(|f| f.bound.clone())
+            .chain(self.skipped.iter().filter_map(|s| s.bound.clone()))
+            .collect()
+    }
+
+    fn generate(&self, generics: &syn::Generics) -> proc_macro2::TokenStream {
+        if self.transparent {
+            return self.generate_transparent(generics);
         }
 
         let struct_fields: Vec<_> = self
@@ -471,74 +1236,293 @@ impl MyStruct {
             .iter()
             .map(|i| {
                 let field = i.field.clone();
-                let ty = i.field_ty.ty.clone();
-                quote! { #field: #ty::new() }
+                let init = i.field_ty.init.clone();
+                quote! { #field: #init }
             })
             .collect();
-        let to_struct_fields: Vec<_> = self.fields.iter().map(|i| i.build.clone()).collect();
-        let selected_matches: Vec<_> = self
+        let to_struct_fields = self.builds();
+        let errors = syn::Ident::new("errors", proc_macro2::Span::mixed_site());
+        let field_idents: Vec<_> = self.fields.iter().map(|i| i.field.clone()).collect();
+        let collect_fields: Vec<_> = self
+            .fields
+            .iter()
+            .map(|i| {
+                let ident = &i.field;
+                let segment = &i.segment;
+                let try_build = &i.try_build;
+                quote! {
+                    let #ident = #errors.field(#segment, #try_build);
+                }
+            })
+            .collect();
+        let focusable: Vec<_> = self
             .fields
             .iter()
             .enumerate()
+            .filter(|(_, i)| !i.readonly)
+            .collect();
+        let focusable_idx: Vec<_> = focusable.iter().map(|(idx, _)| *idx).collect();
+        let first_focusable = focusable_idx.first().copied().unwrap_or(0);
+        let selected_matches: Vec<_> = focusable
+            .iter()
+            .map(|(idx, i)| {
+                let ident = i.field.clone();
+
+                quote! { i if i == #idx => ::reformy_core::FormWidget::input(&mut self.#ident, theinput.clone()), }
+            })
+            .collect();
+        let blur_matches: Vec<_> = focusable
+            .iter()
             .map(|(idx, i)| {
                 let ident = i.field.clone();
 
-                quote! { i if i == #idx => self.#ident.input(theinput.clone()), }
+                quote! { i if i == #idx => ::reformy_core::FormWidget::blur(&mut self.#ident), }
+            })
+            .collect();
+        let focus_matches: Vec<_> = focusable
+            .iter()
+            .map(|(idx, i)| {
+                let ident = i.field.clone();
+
+                quote! {
+                    i if i == #idx => if first {
+                        ::reformy_core::FormWidget::focus_first(&mut self.#ident)
+                    } else {
+                        ::reformy_core::FormWidget::focus_last(&mut self.#ident)
+                    },
+                }
+            })
+            .collect();
+        let last_focusable = focusable_idx.last().copied().unwrap_or(0);
+        let path_matches: Vec<_> = focusable
+            .iter()
+            .map(|(idx, i)| {
+                let ident = &i.field;
+                let segment = &i.segment;
+                quote! {
+                    #idx => ::reformy_core::FormWidget::focus_path(&self.#ident).prefixed(#segment),
+                }
+            })
+            .collect();
+        let set_focus_matches: Vec<_> = focusable
+            .iter()
+            .map(|(idx, i)| {
+                let ident = &i.field;
+                let segment = &i.segment;
+                quote! {
+                    #segment => {
+                        self.selected = #idx;
+                        ::reformy_core::FormWidget::set_focus_segments(&mut self.#ident, rest)
+                    }
+                }
             })
             .collect();
+        let field_enum = self.field_enum();
         let render_calls: Vec<_> = self.fields.iter().map(|i| i.render.clone()).collect();
-        let field_count = struct_fields.len();
+        let label_width = self.label_width();
+        let field_defaults = self.field_defaults();
         let name = &self.name;
+        let vis = &self.vis;
         let form_name = self.form_name();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let (marker, marker_init) = marker_field(name, generics);
+        // A variant's form is filled by the form of its enum.
+        let fill_default = match self.variant {
+            Some(_) => quote! {},
+            None => fill_default(name, generics, self.default),
+        };
 
-        let buildent = if let Some(variant) = &self.variant {
+        let path = if let Some(variant) = &self.variant {
             quote! { #name::#variant }
         } else {
             quote! { #name }
         };
 
+        let buildent = match self.kind {
+            FieldsKind::Named => quote! { #path { #(#to_struct_fields,)* } },
+            FieldsKind::Unnamed => quote! { #path ( #(#to_struct_fields,)* ) },
+        };
+        let pattern = self.pattern(&path);
+        let build = validated(
+            self.validate_with.as_ref(),
+            quote! { ::core::result::Result::Ok(#buildent) },
+        );
+
+        // A variant's form only fills in when the value is that variant.
+        let set_fields = quote! {
+            #(::reformy_core::Form::set_value(&mut self.#field_idents, #field_idents);)*
+        };
+        let set_value = if self.variant.is_some() {
+            quote! {
+                if let #pattern = value {
+                    #set_fields
+                }
+            }
+        } else {
+            quote! {
+                let #pattern = value;
+                #set_fields
+            }
+        };
+
         quote! {
-            pub struct #form_name {
+            #vis struct #form_name #impl_generics #where_clause {
                 #(#struct_fields,)*
                 pub selected: usize,
+                focused: bool,
+                #marker
             }
 
-            impl #form_name {
+            #field_enum
+
+            impl #impl_generics #form_name #ty_generics #where_clause {
                 pub fn new() -> Self {
-                    Self {
+                    let form = Self {
                         #(#field_inits,)*
-                        selected: 0,
+                        selected: #first_focusable,
+                        focused: false,
+                        #marker_init
+                    };
+                    #fill_default
+                    #field_defaults
+                    form
+                }
+
+                /// Moves the focus inside the selected field to its first or last field.
+                fn focus_selected(&mut self, first: bool) {
+                    match self.selected {
+                        #(#focus_matches)*
+                        _ => {
+                            let _ = first;
+                        }
                     }
                 }
 
-                pub fn form_height(&self) -> u16 {
+                fn blur_selected(&mut self) {
+                    match self.selected {
+                        #(#blur_matches)*
+                        _ => {}
+                    }
+                }
+            }
+
+            impl #impl_generics ::std::default::Default for #form_name #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl #impl_generics ::reformy_core::Form for #form_name #ty_generics #where_clause {
+                type Output = #name #ty_generics;
+
+                fn try_build(&self) -> ::core::result::Result<Self::Output, ::reformy_core::FormErrors> {
+                    let mut #errors = ::reformy_core::FormErrors::new();
+                    #(#collect_fields)*
+
+                    let (#(::core::option::Option::Some(#field_idents),)*) = (#(#field_idents,)*) else {
+                        return ::core::result::Result::Err(#errors);
+                    };
+
+                    #build
+                }
+
+                fn set_value(&mut self, value: &Self::Output) {
+                    #set_value
+                }
+            }
+
+            impl #impl_generics ::reformy_core::FormWidget for #form_name #ty_generics #where_clause {
+                fn form_height(&self) -> u16 {
                     0 #( + #height_exprs )* + 1
                 }
 
-                pub fn input(&mut self, input: tui_textarea::Input) -> bool {
+                fn input(&mut self, input: tui_textarea::Input) -> bool {
+                    // Only the form with the focus gets key presses.
+                    self.focused = true;
                     let theinput = input.clone();
                     let handled = match self.selected {
                         #(#selected_matches)*
-                        _ => unreachable!(),
+                        _ => false,
                     };
 
                     if handled {
                         return true;
                     }
 
-                    match input.key {
-                        tui_textarea::Key::Down if self.selected < #field_count - 1 => {
-                            self.selected += 1;
-                            true
-                        }
-                        tui_textarea::Key::Up if self.selected > 0 => {
-                            self.selected -= 1;
+                    // Down and Tab enter the next field at its first field, Up and
+                    // Shift+Tab the previous one at its last field. Alt+Up/Alt+Down
+                    // are left to a surrounding list to move this element.
+                    let forward = match input.key {
+                        tui_textarea::Key::Down if !input.alt => true,
+                        tui_textarea::Key::Up if !input.alt => false,
+                        tui_textarea::Key::Tab => !input.shift,
+                        _ => return false,
+                    };
+
+                    // Read-only fields are skipped when moving the focus.
+                    let focusable: &[usize] = &[#(#focusable_idx),*];
+                    let next = if forward {
+                        focusable.iter().copied().find(|&i| i > self.selected)
+                    } else {
+                        focusable.iter().copied().rev().find(|&i| i < self.selected)
+                    };
+
+                    match next {
+                        ::core::option::Option::Some(next) => {
+                            self.blur_selected();
+                            self.selected = next;
+                            self.focus_selected(forward);
                             true
                         }
+                        ::core::option::Option::None => false,
+                    }
+                }
+
+                /// Only blurs the selected field if the form had the focus, so that
+                /// e.g. `set_focus` on a new form doesn't mark its first field as
+                /// touched.
+                fn blur(&mut self) {
+                    if self.focused {
+                        self.focused = false;
+                        self.blur_selected();
+                    }
+                }
+
+                fn focus_first(&mut self) {
+                    self.focused = true;
+                    self.selected = #first_focusable;
+                    self.focus_selected(true);
+                }
+
+                fn focus_last(&mut self) {
+                    self.focused = true;
+                    self.selected = #last_focusable;
+                    self.focus_selected(false);
+                }
+
+                fn focus_path(&self) -> ::reformy_core::FieldPath {
+                    match self.selected {
+                        #(#path_matches)*
+                        _ => ::reformy_core::FieldPath::root(),
+                    }
+                }
+
+                fn set_focus_segments(&mut self, path: &[::std::string::String]) -> bool {
+                    self.focused = true;
+                    let ::core::option::Option::Some((segment, rest)) = path.split_first() else {
+                        ::reformy_core::FormWidget::focus_first(self);
+                        return true;
+                    };
+                    match segment.as_str() {
+                        #(#set_focus_matches)*
                         _ => false,
                     }
                 }
 
+                fn set_error_display(&mut self, display: ::reformy_core::ErrorDisplay) {
+                    #(::reformy_core::FormWidget::set_error_display(&mut self.#field_idents, display);)*
+                }
+
                 fn render(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer, state: bool) {
                     use ratatui::layout::{Layout, Direction, Constraint};
                     use ratatui::widgets::WidgetRef;
@@ -547,134 +1531,430 @@ impl MyStruct {
                         .direction(Direction::Vertical)
                         .constraints(vec![#(Constraint::Length(#height_exprs)),*])
                         .split(area);
+                    let label_width = #label_width;
 
-                    let title = ratatui::widgets::Paragraph::new(stringify!(self.name).to_string() + ":")
+                    let title = ratatui::widgets::Paragraph::new(::std::string::ToString::to_string(stringify!(self.name)) + ":")
         .style(ratatui::style::Style::default().add_modifier(ratatui::style::Modifier::BOLD));
 
                     #(#render_calls)*
 
                 }
-
-                pub fn build(&self) -> Option<#name> {
-                    Some(#buildent {
-                        #(#to_struct_fields,)*
-                    })
-                }
             }
         }
     }
-}
 
-fn extract_field(idx: usize, field: &Field) -> StructField {
-    let ident = field.ident.as_ref().unwrap();
-    let ty = &field.ty;
+    /// Generates the form of a single-field struct, which renders and behaves
+    /// exactly like the form of its field.
+    fn generate_transparent(&self, generics: &syn::Generics) -> proc_macro2::TokenStream {
+        let field = &self.fields[0];
+        let ident = &field.field;
+        let ty = &field.field_ty.ty;
+        let init = &field.field_ty.init;
+        let build = &field.build;
+        let collect = &field.try_build;
+        let name = &self.name;
+        let vis = &self.vis;
+        let form_name = self.form_name();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let (marker, marker_init) = marker_field(name, generics);
+        let fill_default = fill_default(name, generics, self.default);
+        let field_defaults = self.field_defaults();
+
+        let buildent = match self.kind {
+            FieldsKind::Named => quote! { #name { #build } },
+            FieldsKind::Unnamed => quote! { #name ( #build ) },
+        };
+        let pattern = self.pattern(&quote! { #name });
+        let build = validated(
+            self.validate_with.as_ref(),
+            quote! { #collect.map(|#ident| #buildent) },
+        );
 
-    if is_nested_field(field) {
-        let ty: syn::Type = parse_str(&format!(
-            "{}Form",
-            ty.to_token_stream().to_string().replace(' ', "")
-        ))
-        .unwrap();
+        quote! {
+            #vis struct #form_name #impl_generics #where_clause {
+                pub #ident: #ty,
+                #marker
+            }
 
-        let to_fields = quote! { #ident: self.#ident.build()? };
+            impl #impl_generics #form_name #ty_generics #where_clause {
+                pub fn new() -> Self {
+                    let form = Self {
+                        #ident: #init,
+                        #marker_init
+                    };
+                    #fill_default
+                    #field_defaults
+                    form
+                }
+            }
 
-        let render = quote! {
-            {
-                let chunk = chunks[#idx];
-                let cols = ratatui::layout::Layout::default()
-                    .direction(ratatui::layout::Direction::Vertical)
-                    .constraints([
-                        ratatui::layout::Constraint::Length(1),
-                        ratatui::layout::Constraint::Min(0)
-                    ])
-                    .split(chunk);
-
-                let label = if self.selected == #idx && state {
-                    ratatui::widgets::Paragraph::new(format!("> {}:", stringify!(#ident)))
-                        .style(ratatui::style::Style::default().fg(ratatui::style::Color::Yellow))
-                } else {
-                    ratatui::widgets::Paragraph::new(format!("{}:", stringify!(#ident)))
-                };
+            impl #impl_generics ::std::default::Default for #form_name #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
 
-                label.render_ref(cols[0], buf);
+            impl #impl_generics ::reformy_core::Form for #form_name #ty_generics #where_clause {
+                type Output = #name #ty_generics;
 
-                let cols = ratatui::layout::Layout::default()
-                    .direction(ratatui::layout::Direction::Horizontal)
-                    .constraints([
-                        ratatui::layout::Constraint::Length(4),
-                        ratatui::layout::Constraint::Min(0)
-                    ])
-                    .split(cols[1]);
+                fn try_build(&self) -> ::core::result::Result<Self::Output, ::reformy_core::FormErrors> {
+                    #build
+                }
 
-                ratatui::widgets::StatefulWidgetRef::render_ref(
-                    &self.#ident,
-                    cols[1],
-                    buf,
-                    &mut (self.selected == #idx && state),
-                );
+                fn set_value(&mut self, value: &Self::Output) {
+                    let #pattern = value;
+                    ::reformy_core::Form::set_value(&mut self.#ident, #ident);
+                }
             }
-        };
 
-        StructField {
-            field: ident.clone(),
-            field_ty: FieldType { ty, is_leaf: false },
-            build: to_fields,
-            render,
-        }
-    } else {
-        let to_fields = quote! { #ident: self.#ident.value()? };
-        let render = quote! {
-            {
-                let chunk = chunks[#idx];
-                let cols = ratatui::layout::Layout::default()
-                    .direction(ratatui::layout::Direction::Horizontal)
-                    .constraints([
-                        ratatui::layout::Constraint::Length(12),
-                        ratatui::layout::Constraint::Min(0)
-                    ])
-                    .split(chunk);
-
-                let label = if self.selected == #idx && state {
-                    ratatui::widgets::Paragraph::new(format!("> {}", stringify!(#ident)))
-                        .style(ratatui::style::Style::default().fg(ratatui::style::Color::Yellow))
-                } else {
-                    ratatui::widgets::Paragraph::new(stringify!(#ident))
-                };
+            impl #impl_generics ::reformy_core::FormWidget for #form_name #ty_generics #where_clause {
+                fn form_height(&self) -> u16 {
+                    ::reformy_core::FormWidget::form_height(&self.#ident)
+                }
+
+                fn is_inline(&self) -> bool {
+                    ::reformy_core::FormWidget::is_inline(&self.#ident)
+                }
+
+                fn input(&mut self, input: tui_textarea::Input) -> bool {
+                    ::reformy_core::FormWidget::input(&mut self.#ident, input)
+                }
+
+                fn render(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer, state: bool) {
+                    ::reformy_core::FormWidget::render(&self.#ident, area, buf, state);
+                }
+
+                fn visible_error(&self) -> ::core::option::Option<::std::string::String> {
+                    ::reformy_core::FormWidget::visible_error(&self.#ident)
+                }
+
+                fn is_empty(&self) -> bool {
+                    ::reformy_core::FormWidget::is_empty(&self.#ident)
+                }
 
-                label.render_ref(cols[0], buf);
-                self.#ident.input.render(cols[1], buf);
+                fn clear(&mut self) {
+                    ::reformy_core::FormWidget::clear(&mut self.#ident);
+                }
+
+                fn set_placeholder(&mut self, text: &str) {
+                    ::reformy_core::FormWidget::set_placeholder(&mut self.#ident, text);
+                }
+
+                fn blur(&mut self) {
+                    ::reformy_core::FormWidget::blur(&mut self.#ident);
+                }
+
+                fn focus_first(&mut self) {
+                    ::reformy_core::FormWidget::focus_first(&mut self.#ident);
+                }
+
+                fn focus_last(&mut self) {
+                    ::reformy_core::FormWidget::focus_last(&mut self.#ident);
+                }
+
+                fn focus_path(&self) -> ::reformy_core::FieldPath {
+                    ::reformy_core::FormWidget::focus_path(&self.#ident)
+                }
+
+                fn set_focus_segments(&mut self, path: &[::std::string::String]) -> bool {
+                    ::reformy_core::FormWidget::set_focus_segments(&mut self.#ident, path)
+                }
+
+                fn set_error_display(&mut self, display: ::reformy_core::ErrorDisplay) {
+                    ::reformy_core::FormWidget::set_error_display(&mut self.#ident, display);
+                }
             }
-        };
-        StructField {
-            field: ident.clone(),
-            field_ty: FieldType {
-                ty: parse2(quote! {::reformy_core::Filtext::<#ty>}).unwrap(),
-                is_leaf: true,
-            },
-            build: to_fields,
-            render,
         }
     }
 }
 
-fn generate_struct_form(name: syn::Ident, fields: syn::Fields) -> MyObject {
-    let named_fields = match fields {
-        syn::Fields::Named(fields) => fields.named,
-        _ => {
-            panic!("only named fields")
-        }
+/// Describes a `#[form(skip)]` field, built from `default` or its `Default`.
+fn skip_field(
+    index: usize,
+    field: &Field,
+    default: Option<syn::Expr>,
+) -> syn::Result<SkippedField> {
+    let ty = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => quote! { #ident: },
+        None => quote! {},
     };
 
-    let mystruct = MyStruct::new(name.clone(), None, named_fields.into_iter().collect());
+    let (build, bound) = match default {
+        Some(default) => (quote! { #member #default }, None),
+        None => (
+            quote_spanned! { ty.span()=> #member <#ty as ::std::default::Default>::default() },
+            Some(parse2(quote! { #ty: ::std::default::Default })?),
+        ),
+    };
 
-    MyObject::Struct(mystruct)
+    Ok(SkippedField {
+        index,
+        build,
+        bound,
+    })
 }
 
-fn is_nested_field(field: &Field) -> bool {
-    field.attrs.iter().any(|attr| {
-        attr.path().is_ident("form")
-            && attr
-                .parse_args::<syn::Ident>()
-                .map_or(false, |i| i == "nested")
+/// Describes the field at `index` in the struct, rendered at `idx` in the form.
+fn extract_field(
+    index: usize,
+    idx: usize,
+    field: &Field,
+    mut attrs: FieldAttrs,
+    rename: Option<RenameRule>,
+) -> syn::Result<StructField> {
+    let ty = &field.ty;
+
+    let (ident, segment) = match &field.ident {
+        Some(ident) => (ident.clone(), ident.unraw().to_string()),
+        None => (format_ident!("_{}", index), index.to_string()),
+    };
+    let label = attrs
+        .label
+        .take()
+        .unwrap_or_else(|| match (&field.ident, rename) {
+            (Some(_), Some(rule)) => rule.apply(&segment),
+            _ => segment.clone(),
+        });
+
+    let (form_ty, init, bound) = match attrs.kind.unwrap_or_default() {
+        _ if attrs.is_number() => {
+            let min = attrs.min.iter();
+            let max = attrs.max.iter();
+            let step = attrs.step.iter();
+            let slider = (attrs.kind == Some(FieldKind::Slider)).then(|| quote! { .slider() });
+            (
+                quote_spanned! { ty.span()=> ::reformy_core::NumberForm::<#ty> },
+                quote_spanned! { ty.span()=>
+                    ::reformy_core::NumberForm::<#ty>::new()
+                        #(.min(#min))*
+                        #(.max(#max))*
+                        #(.step(#step))*
+                        #slider
+                },
+                quote! { #ty: ::reformy_core::Number },
+            )
+        }
+        FieldKind::Leaf => (
+            quote_spanned! { ty.span()=> ::reformy_core::Filtext::<#ty> },
+            quote_spanned! { ty.span()=> ::reformy_core::Filtext::<#ty>::new() },
+            quote! { #ty: ::std::default::Default + ::std::fmt::Display + ::std::str::FromStr<Err: ::std::fmt::Display> },
+        ),
+        FieldKind::Switch => (
+            quote_spanned! { ty.span()=> ::reformy_core::Checkbox },
+            quote_spanned! { ty.span()=> ::reformy_core::Checkbox::switch() },
+            quote! { ::reformy_core::Checkbox: ::reformy_core::Form<Output = #ty> },
+        ),
+        FieldKind::Multiselect => (
+            quote_spanned! { ty.span()=> ::reformy_core::MultiSelect::<#ty> },
+            quote_spanned! { ty.span()=> ::reformy_core::MultiSelect::<#ty>::new() },
+            quote! { #ty: ::reformy_core::ChoiceSet },
+        ),
+        FieldKind::Flags => (
+            quote_spanned! { ty.span()=> ::reformy_core::FlagsForm::<#ty> },
+            quote_spanned! { ty.span()=> ::reformy_core::FlagsForm::<#ty>::new() },
+            quote! { #ty: ::reformy_core::bitflags::Flags },
+        ),
+        FieldKind::Slider => unreachable!("a slider is always a number field"),
+        FieldKind::Multiline => {
+            let rows = match attrs.rows {
+                Some(TextRows::Fixed(rows)) => quote! { ::reformy_core::TextRows::Fixed(#rows) },
+                Some(TextRows::Grow(rows)) => quote! { ::reformy_core::TextRows::Grow(#rows) },
+                None => {
+                    quote! { ::reformy_core::TextRows::Grow(::reformy_core::TextRows::DEFAULT_MAX) }
+                }
+            };
+            (
+                quote_spanned! { ty.span()=> ::reformy_core::Filtext::<#ty> },
+                quote_spanned! { ty.span()=> ::reformy_core::Filtext::<#ty>::new().rows(#rows) },
+                quote! { #ty: ::std::default::Default + ::std::fmt::Display + ::std::str::FromStr<Err: ::std::fmt::Display> },
+            )
+        }
+        FieldKind::Secret => (
+            quote_spanned! { ty.span()=> ::reformy_core::SecretForm::<#ty> },
+            quote_spanned! { ty.span()=> ::reformy_core::SecretForm::<#ty>::new() },
+            quote! { #ty: ::std::default::Default + ::std::fmt::Display + ::std::str::FromStr<Err: ::std::fmt::Display> },
+        ),
+        FieldKind::Nested => (
+            quote_spanned! { ty.span()=> <#ty as ::reformy_core::FormRenderable>::Form },
+            quote_spanned! { ty.span()=> <#ty as ::reformy_core::FormRenderable>::form() },
+            quote_spanned! { ty.span()=> #ty: ::reformy_core::FormRenderable },
+        ),
+    };
+
+    let init = match &attrs.placeholder {
+        Some(placeholder) => quote! {
+            {
+                let mut form = #init;
+                ::reformy_core::FormWidget::set_placeholder(&mut form, #placeholder);
+                form
+            }
+        },
+        None => init,
+    };
+
+    // Named fields use the shorthand, the local is named after the field.
+    let to_fields = quote! { #ident };
+
+    let try_build = match &attrs.validate {
+        Some(validate) => quote! {
+            ::reformy_core::Form::try_build(&self.#ident).and_then(|value| {
+                #validate(&value).map_err(::reformy_core::FormErrors::single)?;
+                ::core::result::Result::Ok(value)
+            })
+        },
+        None => quote! { ::reformy_core::Form::try_build(&self.#ident) },
+    };
+
+    // Read-only fields are never selected, their label is dimmed instead.
+    let dim_label = attrs.readonly.then(|| {
+        quote! {
+            let label = label.style(ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray));
+        }
+    });
+
+    let render_help = attrs.help.as_ref().map(|help| {
+        quote! {
+            let chunk = if self.focused && self.selected == #idx {
+                let help_area = ratatui::layout::Rect {
+                    y: chunk.y + chunk.height.saturating_sub(1),
+                    height: chunk.height.min(1),
+                    ..chunk
+                };
+                ratatui::widgets::Paragraph::new(#help)
+                    .style(ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray))
+                    .render_ref(help_area, buf);
+                ratatui::layout::Rect {
+                    height: chunk.height.saturating_sub(1),
+                    ..chunk
+                }
+            } else {
+                chunk
+            };
+        }
+    });
+
+    let render = quote! {
+        let chunk = chunks[#idx];
+        #render_help
+
+        if ::reformy_core::FormWidget::is_inline(&self.#ident) {
+            let cols = ratatui::layout::Layout::default()
+                .direction(ratatui::layout::Direction::Horizontal)
+                .constraints([
+                    ratatui::layout::Constraint::Length(label_width),
+                    ratatui::layout::Constraint::Min(0)
+                ])
+                .split(chunk);
+
+            let label = if self.selected == #idx && state {
+                ratatui::widgets::Paragraph::new(format!("> {}", #label))
+                    .style(ratatui::style::Style::default().fg(ratatui::style::Color::Yellow))
+            } else {
+                ratatui::widgets::Paragraph::new(#label)
+            };
+            #dim_label
+
+            let label = if ::reformy_core::FormWidget::visible_error(&self.#ident).is_some() {
+                label.style(ratatui::style::Style::default().fg(ratatui::style::Color::Red))
+            } else {
+                label
+            };
+
+            label.render_ref(cols[0], buf);
+
+            ::reformy_core::FormWidget::render(
+                &self.#ident,
+                cols[1],
+                buf,
+                self.selected == #idx && state,
+            );
+        } else {
+            let cols = ratatui::layout::Layout::default()
+                .direction(ratatui::layout::Direction::Vertical)
+                .constraints([
+                    ratatui::layout::Constraint::Length(1),
+                    ratatui::layout::Constraint::Min(0)
+                ])
+                .split(chunk);
+
+            let label = if self.selected == #idx && state {
+                ratatui::widgets::Paragraph::new(format!("> {}:", #label))
+                    .style(ratatui::style::Style::default().fg(ratatui::style::Color::Yellow))
+            } else {
+                ratatui::widgets::Paragraph::new(format!("{}:", #label))
+            };
+            #dim_label
+
+            label.render_ref(cols[0], buf);
+
+            let cols = ratatui::layout::Layout::default()
+                .direction(ratatui::layout::Direction::Horizontal)
+                .constraints([
+                    ratatui::layout::Constraint::Length(4),
+                    ratatui::layout::Constraint::Min(0)
+                ])
+                .split(cols[1]);
+
+            ::reformy_core::FormWidget::render(
+                &self.#ident,
+                cols[1],
+                buf,
+                self.selected == #idx && state,
+            );
+        }
+    };
+
+    Ok(StructField {
+        field: ident.clone(),
+        label,
+        help: attrs.help,
+        readonly: attrs.readonly,
+        default: attrs.default,
+        segment,
+        index,
+        field_ty: FieldType {
+            ty: parse2(form_ty)?,
+            init,
+        },
+        bound: parse2(bound)?,
+        try_build,
+        build: to_fields,
+        render,
     })
 }
+
+fn generate_struct_form(
+    name: syn::Ident,
+    vis: syn::Visibility,
+    fields: syn::Fields,
+    transparent: bool,
+    rename: Option<RenameRule>,
+    validate_with: Option<syn::Path>,
+) -> syn::Result<MyObject> {
+    if fields.is_empty() {
+        let msg = match fields {
+            syn::Fields::Unit => "unit structs are not supported by FormRenderable",
+            _ => "structs without fields are not supported by FormRenderable",
+        };
+        return Err(syn::Error::new_spanned(name, msg));
+    }
+
+    if transparent && fields.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "#[form(transparent)] requires exactly one field",
+        ));
+    }
+
+    let (kind, fields): (FieldsKind, Vec<Field>) = match fields {
+        syn::Fields::Named(fields) => (FieldsKind::Named, fields.named.into_iter().collect()),
+        syn::Fields::Unnamed(fields) => (FieldsKind::Unnamed, fields.unnamed.into_iter().collect()),
+        syn::Fields::Unit => unreachable!(),
+    };
+
+    let mut mystruct = MyStruct::new(name.clone(), vis, None, kind, transparent, fields, rename)?;
+    mystruct.validate_with = validate_with;
+
+    Ok(MyObject::Struct(mystruct))
+}
@@ -0,0 +1,49 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(default)]
+struct Envelope<T> {
+    subject: T,
+    priority: u8,
+}
+
+impl<T: Default> Default for Envelope<T> {
+    fn default() -> Self {
+        Self {
+            subject: T::default(),
+            priority: 3,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(default)]
+enum Delivery<T> {
+    Pickup,
+    Courier { note: T },
+}
+
+impl<T: Default> Default for Delivery<T> {
+    fn default() -> Self {
+        Self::Courier { note: T::default() }
+    }
+}
+
+#[test]
+fn generic_struct_starts_from_default() {
+    let form = Envelope::<String>::form();
+    assert_eq!(
+        form.try_build(),
+        Ok(Envelope {
+            subject: String::new(),
+            priority: 3
+        })
+    );
+}
+
+#[test]
+fn generic_enum_starts_from_default() {
+    let form = Delivery::<u8>::form();
+    assert_eq!(form.try_build(), Ok(Delivery::Courier { note: 0 }));
+}
@@ -0,0 +1,55 @@
+use reformy::FormRenderable;
+use reformy_core::{ErrorDisplay, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Booking {
+    guests: u8,
+    note: String,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+/// A form with an invalid `guests` field that still has the focus.
+fn invalid_form(display: ErrorDisplay) -> BookingForm {
+    let mut form = Booking::form();
+    form.set_error_display(display);
+    form.set_focus("guests");
+    form.input(key(Key::Char('x')));
+    form
+}
+
+#[test]
+fn live_errors_show_while_typing() {
+    let form = invalid_form(ErrorDisplay::Live);
+    assert!(form.guests.visible_error().is_some());
+    assert_eq!(form.form_height(), Booking::form().form_height() + 1);
+}
+
+#[test]
+fn touched_errors_show_once_the_field_loses_focus() {
+    let mut form = invalid_form(ErrorDisplay::Touched);
+    let height = form.form_height();
+    assert_eq!(form.guests.visible_error(), None);
+    assert_eq!(height, Booking::form().form_height());
+
+    assert!(form.input(key(Key::Tab)));
+    assert!(form.guests.visible_error().is_some());
+    assert_eq!(form.form_height(), height + 1);
+}
+
+#[test]
+fn hidden_errors_only_fail_the_build() {
+    let mut form = invalid_form(ErrorDisplay::Hidden);
+    let height = form.form_height();
+
+    assert!(form.input(key(Key::Tab)));
+    assert_eq!(form.guests.visible_error(), None);
+    assert_eq!(form.form_height(), height);
+    assert!(form.try_build().is_err());
+}
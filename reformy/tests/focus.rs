@@ -0,0 +1,203 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(FormRenderable)]
+struct Contact {
+    name: String,
+    email: String,
+    phone: String,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+fn ctrl(key: Key) -> Input {
+    Input {
+        key,
+        ctrl: true,
+        ..Input::default()
+    }
+}
+
+#[test]
+fn home_and_end_stay_in_text_field() {
+    let mut form = Contact::form();
+    form.set_focus("email");
+
+    assert!(form.root_input(key(Key::End), true));
+    assert!(form.root_input(key(Key::Home), true));
+    assert_eq!(form.focus_path(), FieldPath::from("email"));
+}
+
+#[test]
+fn ctrl_home_and_ctrl_end_jump_to_first_and_last_field() {
+    let mut form = Contact::form();
+    form.set_focus("email");
+
+    assert!(form.root_input(ctrl(Key::End), true));
+    assert_eq!(form.focus_path(), FieldPath::from("phone"));
+    assert!(form.root_input(ctrl(Key::Home), true));
+    assert_eq!(form.focus_path(), FieldPath::from("name"));
+}
+
+#[derive(FormRenderable)]
+#[form(dropdown)]
+enum Plan {
+    Free,
+    Pro,
+}
+
+#[derive(FormRenderable)]
+struct Signup {
+    plan: Plan,
+    email: String,
+}
+
+#[test]
+fn tab_leaves_an_open_dropdown() {
+    let mut form = Signup::form();
+    assert!(form.root_input(key(Key::Enter), false));
+    assert!(form.plan.selector.open);
+
+    assert!(form.root_input(key(Key::Tab), false));
+    assert!(!form.plan.selector.open);
+    assert_eq!(form.focus_path(), FieldPath::from("email"));
+}
+
+#[derive(FormRenderable)]
+struct Address {
+    street: String,
+    #[form(readonly, default = "Earth".to_string())]
+    planet: String,
+    city: String,
+}
+
+#[derive(FormRenderable)]
+struct Account {
+    name: String,
+    #[form(readonly, default = 7)]
+    id: u32,
+    address: Address,
+    email: String,
+}
+
+fn shift_tab() -> Input {
+    Input {
+        key: Key::Tab,
+        shift: true,
+        ..Input::default()
+    }
+}
+
+fn focus_order(form: &mut impl Form, input: Input, wrap: bool) -> Vec<String> {
+    let mut order = vec![form.focus_path().to_string()];
+    while form.root_input(input.clone(), wrap) && order.len() < 8 {
+        order.push(form.focus_path().to_string());
+    }
+    order
+}
+
+#[test]
+fn tab_visits_nested_fields_and_skips_readonly_ones() {
+    let mut form = Account::form();
+    assert_eq!(
+        focus_order(&mut form, key(Key::Tab), false),
+        ["name", "address.street", "address.city", "email"]
+    );
+}
+
+#[test]
+fn shift_tab_visits_fields_backwards() {
+    let mut form = Account::form();
+    form.set_focus("email");
+    assert_eq!(
+        focus_order(&mut form, shift_tab(), false),
+        ["email", "address.city", "address.street", "name"]
+    );
+}
+
+#[test]
+fn tab_wraps_around() {
+    let mut form = Account::form();
+    form.set_focus("email");
+    assert!(form.root_input(key(Key::Tab), true));
+    assert_eq!(form.focus_path(), FieldPath::from("name"));
+    assert!(form.root_input(shift_tab(), true));
+    assert_eq!(form.focus_path(), FieldPath::from("email"));
+}
+
+#[test]
+fn set_focus_by_field_enum() {
+    let mut form = Account::form();
+
+    assert!(form.set_focus(AccountField::Email));
+    assert_eq!(form.focus_path(), FieldPath::from(AccountField::Email));
+
+    assert!(form.set_focus(AccountField::Address));
+    assert_eq!(form.focus_path(), FieldPath::from("address.street"));
+
+    let city = FieldPath::from(AccountField::Address).join(AddressField::City.segment());
+    assert!(form.set_focus(city.clone()));
+    assert_eq!(form.focus_path(), city);
+}
+
+#[test]
+fn set_focus_on_a_missing_field_fails() {
+    let mut form = Account::form();
+    assert!(!form.set_focus("address.country"));
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+enum Role {
+    Guest,
+    Member { since: u16 },
+}
+
+#[test]
+fn set_focus_in_the_selected_variant() {
+    let mut form = Role::form();
+    form.set_value(&Role::Member { since: 2020 });
+
+    let since = FieldPath::from(RoleField::Member).join(RoleMemberField::Since.segment());
+    assert!(form.set_focus(since.clone()));
+    assert_eq!(form.focus_path(), since);
+}
+
+fn not_empty(text: &str) -> Result<(), String> {
+    if text.is_empty() {
+        Err("required".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(FormRenderable)]
+struct Parcel {
+    weight: u32,
+    #[form(validate = not_empty)]
+    label: String,
+}
+
+#[derive(FormRenderable)]
+struct Shipment {
+    note: String,
+    parcel: Parcel,
+}
+
+#[test]
+fn focus_first_error_focuses_the_invalid_field() {
+    let mut form = Shipment::form();
+    assert_eq!(form.focus_path(), FieldPath::from("note"));
+
+    assert!(form.focus_first_error());
+    assert_eq!(form.focus_path(), FieldPath::from("parcel.label"));
+
+    form.parcel.label.set_value(&"fragile".to_string());
+    assert!(!form.focus_first_error());
+    assert_eq!(form.focus_path(), FieldPath::from("parcel.label"));
+}
@@ -0,0 +1,50 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Wrapper<T> {
+    label: String,
+    value: T,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Address {
+    street: String,
+}
+
+#[test]
+fn generic_struct_round_trips() {
+    let value = Wrapper {
+        label: "port".to_string(),
+        value: 8080u16,
+    };
+    let form = Wrapper::form_from(&value);
+    assert_eq!(form.try_build(), Ok(value));
+}
+
+#[test]
+fn generic_struct_round_trips_nested_and_boxed_values() {
+    let value = Wrapper {
+        label: "home".to_string(),
+        value: Box::new(Address {
+            street: "Main Street 1".to_string(),
+        }),
+    };
+    let form = Wrapper::form_from(&value);
+    assert_eq!(form.try_build(), Ok(value));
+
+    let mut form = <Wrapper<Vec<u8>>>::form();
+    assert_eq!(
+        form.try_build(),
+        Ok(Wrapper {
+            label: String::new(),
+            value: Vec::new(),
+        })
+    );
+    let value = Wrapper {
+        label: "bytes".to_string(),
+        value: vec![1, 2, 3],
+    };
+    form.set_value(&value);
+    assert_eq!(form.try_build(), Ok(value));
+}
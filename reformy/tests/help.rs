@@ -0,0 +1,80 @@
+use ratatui::{buffer::Buffer, layout::Rect};
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Address {
+    #[form(help = "The street and house number.")]
+    street: String,
+    city: String,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Customer {
+    name: String,
+    addresses: Vec<Address>,
+}
+
+const HELP: &str = "The street and house number.";
+
+/// The text of the rendered form, one line per row.
+fn rendered(form: &impl Form) -> String {
+    let area = Rect::new(0, 0, 60, form.form_height());
+    let mut buf = Buffer::empty(area);
+    form.render(area, &mut buf, true);
+    (0..area.height)
+        .map(|y| {
+            let line: String = (0..area.width).map(|x| buf[(x, y)].symbol()).collect();
+            line.trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+fn address(street: &str) -> Address {
+    Address {
+        street: street.to_string(),
+        city: "Springfield".to_string(),
+    }
+}
+
+fn customer() -> Customer {
+    Customer {
+        name: "Ada".to_string(),
+        addresses: vec![address("Main Street 1"), address("Elm Street 2")],
+    }
+}
+
+#[test]
+fn help_is_only_shown_in_the_focused_form() {
+    let mut form = Customer::form_from(&customer());
+    assert_eq!(rendered(&form).matches(HELP).count(), 0);
+
+    let height = form.form_height();
+    form.set_focus("addresses.1.street");
+    assert_eq!(rendered(&form).matches(HELP).count(), 1);
+    assert_eq!(form.form_height(), height + 1);
+
+    form.set_focus("addresses.1.city");
+    assert_eq!(rendered(&form).matches(HELP).count(), 0);
+    assert_eq!(form.form_height(), height);
+}
+
+#[test]
+fn help_follows_the_focus_between_list_items() {
+    let mut form = Customer::form_from(&customer());
+    form.set_focus("addresses.0.city");
+
+    let down = Input {
+        key: Key::Down,
+        ..Input::default()
+    };
+    assert!(form.input(down));
+    assert_eq!(form.focus_path().to_string(), "addresses.1.street");
+
+    let text = rendered(&form);
+    assert_eq!(text.matches(HELP).count(), 1);
+    let help_row = text.lines().position(|line| line.contains(HELP));
+    let elm_row = text.lines().position(|line| line.contains("Elm Street 2"));
+    assert!(help_row > elm_row);
+}
@@ -0,0 +1,72 @@
+use std::collections::{BTreeMap, HashMap};
+
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Config {
+    labels: BTreeMap<String, String>,
+    limits: HashMap<String, u32>,
+}
+
+#[test]
+fn map_round_trips() {
+    let config = Config {
+        labels: BTreeMap::from([
+            ("env".to_string(), "prod".to_string()),
+            ("team".to_string(), "core".to_string()),
+        ]),
+        limits: HashMap::from([("cpu".to_string(), 4)]),
+    };
+
+    let mut form = Config::form();
+    form.set_value(&config);
+    assert_eq!(form.try_build(), Ok(config));
+}
+
+#[test]
+fn duplicate_key_is_reported_at_its_entry() {
+    let mut form = Config::form();
+    for value in ["prod", "staging"] {
+        form.labels.push();
+        let (key, val) = form.labels.entries.last_mut().unwrap();
+        key.set_value(&"env".to_string());
+        val.set_value(&value.to_string());
+    }
+
+    let errors = form.try_build().unwrap_err();
+    assert_eq!(
+        errors,
+        FormErrors::at(FieldPath::from("labels.1.key"), "duplicate key")
+    );
+}
+
+#[test]
+fn alt_arrows_reorder_entries() {
+    let mut form = Config::form();
+    form.set_value(&Config {
+        labels: BTreeMap::from([
+            ("env".to_string(), "prod".to_string()),
+            ("team".to_string(), "core".to_string()),
+        ]),
+        limits: HashMap::new(),
+    });
+    form.set_focus("labels.0");
+
+    let alt_down = Input {
+        key: Key::Down,
+        alt: true,
+        ..Input::default()
+    };
+    assert!(form.root_input(alt_down, false));
+
+    let keys: Vec<String> = form
+        .labels
+        .entries
+        .iter()
+        .map(|(key, _)| key.try_build().unwrap())
+        .collect();
+    assert_eq!(keys, ["team", "env"]);
+    assert_eq!(form.focus_path(), FieldPath::from("labels.1.value"));
+}
@@ -0,0 +1,80 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Post {
+    title: String,
+    #[form(multiline(max_rows = 3))]
+    body: String,
+    #[form(multiline(rows = 4))]
+    summary: String,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+fn type_text(form: &mut impl Form, text: &str) {
+    for c in text.chars() {
+        let input = match c {
+            '\n' => key(Key::Enter),
+            c => key(Key::Char(c)),
+        };
+        assert!(form.input(input));
+    }
+}
+
+#[test]
+fn enter_inserts_a_newline_in_multiline_fields() {
+    let mut form = Post::form();
+    form.set_focus("body");
+    type_text(&mut form, "first\nsecond");
+
+    assert_eq!(form.focus_path(), FieldPath::from("body"));
+    assert_eq!(form.body.input.lines(), ["first", "second"]);
+    assert_eq!(form.try_build().unwrap().body, "first\nsecond");
+}
+
+#[test]
+fn enter_is_left_to_the_parent_in_single_line_fields() {
+    let mut form = Post::form();
+    form.set_focus("title");
+    assert!(!form.input(key(Key::Enter)));
+}
+
+#[test]
+fn value_string_joins_lines_with_newlines() {
+    let mut form = Post::form();
+    form.body.set_value(&"one\ntwo\n".to_string());
+
+    assert_eq!(form.body.input.lines(), ["one", "two", ""]);
+    assert_eq!(form.body.value_string(), "one\ntwo\n");
+}
+
+#[test]
+fn growing_fields_are_capped_at_max_rows() {
+    let mut form = Post::form();
+    assert_eq!(form.body.form_height(), 1);
+
+    form.set_focus("body");
+    type_text(&mut form, "1\n2");
+    assert_eq!(form.body.form_height(), 2);
+
+    type_text(&mut form, "\n3\n4\n5");
+    assert_eq!(form.body.form_height(), 3);
+    assert_eq!(form.try_build().unwrap().body, "1\n2\n3\n4\n5");
+}
+
+#[test]
+fn fixed_fields_keep_their_rows() {
+    let mut form = Post::form();
+    assert_eq!(form.summary.form_height(), 4);
+
+    form.set_focus("summary");
+    type_text(&mut form, "a\nb\nc\nd\ne");
+    assert_eq!(form.summary.form_height(), 4);
+}
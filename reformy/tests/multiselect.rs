@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FormRenderable)]
+enum Topping {
+    Cheese,
+    Olives,
+    Basil,
+}
+
+reformy_core::bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const EXECUTE = 1 << 2;
+    }
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Pizza {
+    #[form(multiselect)]
+    toppings: Vec<Topping>,
+    #[form(multiselect)]
+    extras: HashSet<Topping>,
+    #[form(flags)]
+    permissions: Permissions,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+fn space() -> Input {
+    key(Key::Char(' '))
+}
+
+#[test]
+fn multiselect_toggles_choices_into_a_vec() {
+    let mut form = Pizza::form();
+    form.set_focus("toppings");
+
+    assert!(form.input(space()));
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(space()));
+    assert_eq!(
+        form.try_build().unwrap().toppings,
+        [Topping::Cheese, Topping::Basil]
+    );
+
+    assert!(form.input(key(Key::Enter)));
+    assert_eq!(form.try_build().unwrap().toppings, [Topping::Cheese]);
+}
+
+#[test]
+fn multiselect_builds_a_hash_set() {
+    let mut form = Pizza::form();
+    form.set_focus("extras");
+
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(space()));
+    assert_eq!(
+        form.try_build().unwrap().extras,
+        HashSet::from([Topping::Olives])
+    );
+}
+
+#[test]
+fn flags_toggle_single_bits() {
+    let mut form = Pizza::form();
+    form.set_focus("permissions");
+    assert_eq!(form.try_build().unwrap().permissions, Permissions::empty());
+
+    assert!(form.input(space()));
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(space()));
+    assert_eq!(
+        form.try_build().unwrap().permissions,
+        Permissions::READ | Permissions::EXECUTE
+    );
+}
+
+#[test]
+fn multiselect_and_flags_round_trip() {
+    let pizza = Pizza {
+        toppings: vec![Topping::Olives, Topping::Basil],
+        extras: HashSet::from([Topping::Cheese, Topping::Basil]),
+        permissions: Permissions::WRITE,
+    };
+    let mut form = Pizza::form();
+    form.set_value(&pizza);
+    assert_eq!(form.try_build(), Ok(pizza));
+
+    form.set_value(&Pizza {
+        toppings: Vec::new(),
+        extras: HashSet::new(),
+        permissions: Permissions::all(),
+    });
+    let built = form.try_build().unwrap();
+    assert!(built.toppings.is_empty());
+    assert!(built.extras.is_empty());
+    assert_eq!(built.permissions, Permissions::all());
+}
@@ -0,0 +1,122 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Order {
+    #[form(min = 1, max = 10, step = 3)]
+    qty: u8,
+    #[form(slider, min = 0, max = 100, step = 25)]
+    volume: u8,
+    #[form(step = 100)]
+    offset: i8,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+fn ctrl(key: Key) -> Input {
+    Input {
+        key,
+        ctrl: true,
+        ..Input::default()
+    }
+}
+
+fn order(qty: u8, volume: u8, offset: i8) -> Order {
+    Order {
+        qty,
+        volume,
+        offset,
+    }
+}
+
+#[test]
+fn new_form_starts_within_the_range() {
+    let form = Order::form();
+    assert_eq!(form.try_build(), Ok(order(1, 0, 0)));
+}
+
+#[test]
+fn ctrl_arrows_step_and_clamp_to_the_range() {
+    let mut form = Order::form();
+    form.set_focus("qty");
+
+    let mut values = Vec::new();
+    for _ in 0..4 {
+        assert!(form.input(ctrl(Key::Up)));
+        values.push(form.try_build().unwrap().qty);
+    }
+    assert_eq!(values, [4, 7, 10, 10]);
+
+    assert!(form.input(ctrl(Key::Down)));
+    assert_eq!(form.try_build().unwrap().qty, 7);
+}
+
+#[test]
+fn values_outside_the_range_fail_to_build() {
+    let mut form = Order::form();
+
+    form.set_value(&order(0, 0, 0));
+    assert_eq!(
+        form.try_build(),
+        Err(FormErrors::at(FieldPath::from("qty"), "must be at least 1"))
+    );
+
+    form.set_value(&order(11, 0, 0));
+    assert_eq!(
+        form.try_build(),
+        Err(FormErrors::at(FieldPath::from("qty"), "must be at most 10"))
+    );
+}
+
+#[test]
+fn slider_steps_with_left_right_and_plus_minus() {
+    let mut form = Order::form();
+    form.set_focus("volume");
+
+    let mut values = Vec::new();
+    for input in [
+        key(Key::Right),
+        key(Key::Char('+')),
+        key(Key::Left),
+        key(Key::Char('-')),
+        key(Key::Char('-')),
+    ] {
+        assert!(form.input(input));
+        values.push(form.try_build().unwrap().volume);
+    }
+    assert_eq!(values, [25, 50, 25, 0, 0]);
+
+    for _ in 0..5 {
+        form.input(key(Key::Right));
+    }
+    assert_eq!(form.try_build().unwrap().volume, 100);
+}
+
+#[test]
+fn slider_ignores_typed_digits() {
+    let mut form = Order::form();
+    form.set_focus("volume");
+
+    assert!(!form.input(key(Key::Char('5'))));
+    assert_eq!(form.try_build().unwrap().volume, 0);
+}
+
+#[test]
+fn steps_saturate_at_the_bounds_of_the_type() {
+    let mut form = Order::form();
+    form.set_value(&order(1, 0, 100));
+    form.set_focus("offset");
+
+    assert!(form.input(ctrl(Key::Up)));
+    assert_eq!(form.try_build().unwrap().offset, i8::MAX);
+
+    form.set_value(&order(1, 0, -100));
+    assert!(form.input(ctrl(Key::Down)));
+    assert_eq!(form.try_build().unwrap().offset, i8::MIN);
+}
@@ -0,0 +1,45 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Settings {
+    newsletter: Option<bool>,
+}
+
+fn space() -> Input {
+    Input {
+        key: Key::Char(' '),
+        ..Input::default()
+    }
+}
+
+#[test]
+fn option_bool_round_trips_none() {
+    let mut form = Settings::form();
+    assert_eq!(form.try_build(), Ok(Settings { newsletter: None }));
+
+    form.set_value(&Settings {
+        newsletter: Some(false),
+    });
+    assert_eq!(
+        form.try_build(),
+        Ok(Settings {
+            newsletter: Some(false)
+        })
+    );
+
+    form.set_value(&Settings { newsletter: None });
+    assert_eq!(form.try_build(), Ok(Settings { newsletter: None }));
+}
+
+#[test]
+fn option_bool_toggles_through_none() {
+    let mut form = Settings::form();
+    let mut built = Vec::new();
+    for _ in 0..3 {
+        form.root_input(space(), false);
+        built.push(form.try_build().unwrap().newsletter);
+    }
+    assert_eq!(built, [Some(true), Some(false), None]);
+}
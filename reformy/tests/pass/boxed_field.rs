@@ -0,0 +1,20 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Nested {
+    street: String,
+}
+
+#[derive(FormRenderable)]
+struct Customer {
+    address: Box<Nested>,
+    billing: Option<Box<Nested>>,
+}
+
+#[derive(FormRenderable)]
+struct Node {
+    name: String,
+    next: Option<Box<Node>>,
+}
+
+fn main() {}
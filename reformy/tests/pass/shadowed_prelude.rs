@@ -0,0 +1,64 @@
+use reformy::FormRenderable;
+use reformy_core::FormErrors;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Error;
+
+#[allow(dead_code)]
+type Result<T> = std::result::Result<T, Error>;
+
+#[allow(dead_code)]
+type Option = Error;
+
+#[allow(dead_code)]
+type String = Error;
+
+#[allow(dead_code)]
+trait From {}
+
+fn check_name(name: &std::string::String) -> std::result::Result<(), std::string::String> {
+    if name.is_empty() {
+        Err("empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn check_user(_user: &User) -> std::result::Result<(), FormErrors> {
+    Ok(())
+}
+
+#[derive(FormRenderable)]
+#[form(validate_with = check_user)]
+struct User {
+    /// The user's name.
+    #[form(validate = check_name)]
+    name: std::string::String,
+    nickname: std::option::Option<std::string::String>,
+    tags: Vec<std::string::String>,
+    role: Role,
+    id: Id,
+    email: Email,
+}
+
+#[derive(FormRenderable)]
+#[form(validate_with = check_role)]
+enum Role {
+    /// A regular user.
+    Member,
+    Admin { level: u8 },
+}
+
+fn check_role(_role: &Role) -> std::result::Result<(), FormErrors> {
+    Ok(())
+}
+
+#[derive(FormRenderable)]
+struct Id(u32);
+
+#[derive(FormRenderable)]
+#[form(transparent)]
+struct Email(std::string::String);
+
+fn main() {}
@@ -0,0 +1,55 @@
+use ratatui::{buffer::Buffer, layout::Rect};
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable};
+
+/// The text of the rendered form, one line per row.
+fn rendered(form: &impl Form) -> String {
+    let area = Rect::new(0, 0, 40, form.form_height());
+    let mut buf = Buffer::empty(area);
+    form.render(area, &mut buf, false);
+    (0..area.height)
+        .map(|y| {
+            let line: String = (0..area.width).map(|x| buf[(x, y)].symbol()).collect();
+            line.trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+macro_rules! rename_test {
+    ($test:ident, $rule:literal, $field:literal, $variant:literal) => {
+        #[test]
+        fn $test() {
+            #[derive(FormRenderable)]
+            #[form(rename_all = $rule)]
+            struct Account {
+                user_name: String,
+            }
+
+            #[derive(FormRenderable)]
+            #[form(rename_all = $rule)]
+            enum Kind {
+                UserName,
+            }
+
+            let text = rendered(&Account::form());
+            assert!(text.contains($field), "{}", text);
+            let text = rendered(&Kind::form());
+            assert!(text.contains($variant), "{}", text);
+        }
+    };
+}
+
+rename_test!(title_case, "Title Case", "User Name", "User Name");
+rename_test!(sentence_case, "Sentence case", "User name", "User name");
+rename_test!(lowercase, "lowercase", "user_name", "username");
+rename_test!(uppercase, "UPPERCASE", "USER_NAME", "USERNAME");
+rename_test!(snake_case, "snake_case", "user_name", "user_name");
+rename_test!(
+    screaming_snake_case,
+    "SCREAMING_SNAKE_CASE",
+    "USER_NAME",
+    "USER_NAME"
+);
+rename_test!(kebab_case, "kebab-case", "user-name", "user-name");
+rename_test!(camel_case, "camelCase", "userName", "userName");
+rename_test!(pascal_case, "PascalCase", "UserName", "UserName");
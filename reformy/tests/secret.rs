@@ -0,0 +1,39 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Card {
+    #[form(secret)]
+    pin: u32,
+}
+
+fn type_text(form: &mut impl Form, text: &str) {
+    for c in text.chars() {
+        form.input(Input {
+            key: Key::Char(c),
+            ..Input::default()
+        });
+    }
+}
+
+#[test]
+fn secret_error_follows_the_text() {
+    let mut form = Card::form();
+    assert_eq!(form.pin.visible_error(), None);
+
+    type_text(&mut form, "x");
+    assert_eq!(form.pin.visible_error(), Some("invalid value".to_string()));
+    assert!(form.try_build().is_err());
+
+    form.set_value(&Card { pin: 1234 });
+    assert_eq!(form.pin.visible_error(), None);
+    assert_eq!(form.try_build(), Ok(Card { pin: 1234 }));
+}
+
+#[test]
+fn secret_is_not_debug_printed() {
+    let mut form = Card::form();
+    form.set_value(&Card { pin: 4321 });
+    assert!(!format!("{:?}", form.pin).contains("4321"));
+}
@@ -0,0 +1,94 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(dropdown)]
+enum Country {
+    Austria,
+    Belgium,
+    Canada,
+    Chile,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(radio)]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+fn type_text(form: &mut impl Form, text: &str) {
+    for c in text.chars() {
+        assert!(form.input(key(Key::Char(c))));
+    }
+}
+
+#[test]
+fn dropdown_typing_filters_and_enter_picks_the_match() {
+    let mut form = Country::form();
+    type_text(&mut form, "ch");
+    assert!(form.selector.open);
+    assert_eq!(form.selector.matches(), [3]);
+
+    assert!(form.input(key(Key::Enter)));
+    assert!(!form.selector.open);
+    assert_eq!(form.try_build(), Ok(Country::Chile));
+}
+
+#[test]
+fn dropdown_arrows_move_between_the_matches() {
+    let mut form = Country::form();
+    type_text(&mut form, "c");
+    assert_eq!(form.selector.matches(), [2, 3]);
+    assert_eq!(form.selector.highlighted, 2);
+
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(key(Key::Down)));
+    assert_eq!(form.selector.highlighted, 3);
+    assert!(form.input(key(Key::Up)));
+    assert!(form.input(key(Key::Enter)));
+    assert_eq!(form.try_build(), Ok(Country::Canada));
+}
+
+#[test]
+fn dropdown_without_a_match_keeps_the_selection() {
+    let mut form = Country::form();
+    form.set_value(&Country::Belgium);
+    form.set_focus("");
+
+    type_text(&mut form, "xyz");
+    assert!(form.selector.matches().is_empty());
+    assert!(form.input(key(Key::Enter)));
+    assert_eq!(form.try_build(), Ok(Country::Belgium));
+
+    type_text(&mut form, "a");
+    assert!(form.input(key(Key::Esc)));
+    assert!(!form.selector.open);
+    assert_eq!(form.try_build(), Ok(Country::Belgium));
+}
+
+#[test]
+fn radio_arrows_move_the_cursor_and_enter_or_space_pick() {
+    let mut form = Size::form();
+    assert!(form.input(key(Key::Down)));
+    assert!(form.input(key(Key::Down)));
+    assert_eq!(form.selector.highlighted, 2);
+    assert_eq!(form.try_build(), Ok(Size::Small));
+
+    assert!(form.input(key(Key::Enter)));
+    assert_eq!(form.try_build(), Ok(Size::Large));
+    assert!(!form.input(key(Key::Down)));
+
+    assert!(form.input(key(Key::Up)));
+    assert!(form.input(key(Key::Char(' '))));
+    assert_eq!(form.try_build(), Ok(Size::Medium));
+}
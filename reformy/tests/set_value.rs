@@ -0,0 +1,81 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable};
+
+#[derive(Clone, Debug, PartialEq, FormRenderable)]
+struct Address {
+    street: String,
+    number: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, FormRenderable)]
+enum Role {
+    Guest,
+    Member { since: u16 },
+    Moderator(String),
+}
+
+#[derive(Clone, Debug, PartialEq, FormRenderable)]
+struct Profile {
+    name: String,
+    age: u8,
+    nickname: Option<String>,
+    address: Option<Address>,
+    tags: Vec<String>,
+    addresses: Vec<Address>,
+    role: Role,
+}
+
+fn profile() -> Profile {
+    Profile {
+        name: "Ada".to_string(),
+        age: 36,
+        nickname: Some("countess".to_string()),
+        address: Some(Address {
+            street: "Main Street".to_string(),
+            number: 12,
+        }),
+        tags: vec!["math".to_string(), "engines".to_string()],
+        addresses: vec![
+            Address {
+                street: "First Street".to_string(),
+                number: 1,
+            },
+            Address {
+                street: "Second Street".to_string(),
+                number: 2,
+            },
+        ],
+        role: Role::Member { since: 1843 },
+    }
+}
+
+#[test]
+fn set_value_round_trips() {
+    let mut form = Profile::form();
+    form.set_value(&profile());
+    assert_eq!(form.try_build(), Ok(profile()));
+}
+
+#[test]
+fn set_value_replaces_the_previous_value() {
+    let mut form = Profile::form();
+    form.set_value(&profile());
+
+    let edited = Profile {
+        nickname: None,
+        address: None,
+        tags: vec!["poetry".to_string()],
+        addresses: Vec::new(),
+        role: Role::Moderator("general".to_string()),
+        ..profile()
+    };
+    form.set_value(&edited);
+    assert_eq!(form.try_build(), Ok(edited));
+}
+
+#[test]
+fn set_value_round_trips_unit_variants() {
+    let mut form = Role::form();
+    form.set_value(&Role::Guest);
+    assert_eq!(form.try_build(), Ok(Role::Guest));
+}
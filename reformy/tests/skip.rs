@@ -0,0 +1,72 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+fn guest() -> String {
+    "guest".to_string()
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Account {
+    #[form(skip)]
+    id: u64,
+    #[form(skip, default = guest())]
+    role: String,
+    name: String,
+    #[form(readonly, default = "today".to_string())]
+    created: String,
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+#[test]
+fn skipped_fields_are_built_from_their_defaults() {
+    let form = Account::form();
+    assert_eq!(
+        form.try_build(),
+        Ok(Account {
+            id: 0,
+            role: "guest".to_string(),
+            name: String::new(),
+            created: "today".to_string(),
+        })
+    );
+}
+
+#[test]
+fn skipped_fields_are_not_taken_from_set_value() {
+    let mut form = Account::form();
+    form.set_value(&Account {
+        id: 7,
+        role: "admin".to_string(),
+        name: "ada".to_string(),
+        created: "yesterday".to_string(),
+    });
+
+    let account = form.try_build().unwrap();
+    assert_eq!((account.id, account.role.as_str()), (0, "guest"));
+    assert_eq!(account.name, "ada");
+    assert_eq!(account.created, "yesterday");
+}
+
+#[test]
+fn readonly_fields_keep_their_value_and_are_never_focused() {
+    let mut form = Account::form();
+    assert!(!form.set_focus("created"));
+    assert!(!form.set_focus("id"));
+
+    form.set_focus("name");
+    assert!(!form.input(key(Key::Tab)));
+    assert!(!form.input(key(Key::Down)));
+    assert_eq!(form.focus_path(), FieldPath::from("name"));
+
+    form.input(key(Key::Char('x')));
+    let account = form.try_build().unwrap();
+    assert_eq!(account.name, "x");
+    assert_eq!(account.created, "today");
+}
@@ -0,0 +1,73 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Point(i32, String);
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(transparent)]
+struct Email(String);
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Contact {
+    email: Email,
+    phone: String,
+}
+
+fn type_text(form: &mut impl Form, text: &str) {
+    for c in text.chars() {
+        form.input(Input {
+            key: Key::Char(c),
+            ..Input::default()
+        });
+    }
+}
+
+#[test]
+fn tuple_struct_round_trips() {
+    let mut form = Point::form();
+    assert_eq!(form.try_build(), Ok(Point(0, String::new())));
+
+    form.set_value(&Point(-3, "origin".to_string()));
+    assert_eq!(form.try_build(), Ok(Point(-3, "origin".to_string())));
+}
+
+#[test]
+fn tuple_struct_fields_are_focused_by_index() {
+    let mut form = Point::form();
+    assert_eq!(form.focus_path(), FieldPath::from("0"));
+
+    assert!(form.set_focus("1"));
+    assert_eq!(form.focus_path(), FieldPath::from("1"));
+    type_text(&mut form, "a");
+    assert_eq!(form.try_build(), Ok(Point(0, "a".to_string())));
+
+    assert!(form.set_focus("0"));
+    assert_eq!(form.focus_path(), FieldPath::from("0"));
+    assert!(!form.set_focus("2"));
+}
+
+#[test]
+fn transparent_newtype_is_edited_as_its_field() {
+    let mut form = Contact::form();
+    form.set_value(&Contact {
+        email: Email("ada@example.com".to_string()),
+        phone: "555".to_string(),
+    });
+    assert_eq!(
+        form.try_build(),
+        Ok(Contact {
+            email: Email("ada@example.com".to_string()),
+            phone: "555".to_string(),
+        })
+    );
+
+    assert!(form.set_focus("email"));
+    assert_eq!(form.focus_path(), FieldPath::from("email"));
+    assert!(form.email.is_inline());
+
+    let mut email = Email::form();
+    type_text(&mut email, "bob");
+    assert_eq!(email.try_build(), Ok(Email("bob".to_string())));
+}
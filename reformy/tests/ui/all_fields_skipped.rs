@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Order {
+    #[form(skip)]
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+#[form(dropdown, radio)]
+enum Shipping {
+    Standard,
+    Express,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+#[form(dropdown)]
+struct Order {
+    item: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+enum Never {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Empty {}
+
+fn main() {}
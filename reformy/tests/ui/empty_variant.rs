@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+enum Shape {
+    Point,
+    Circle(),
+}
+
+fn main() {}
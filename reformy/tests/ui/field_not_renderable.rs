@@ -0,0 +1,10 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Order {
+    item: Item,
+}
+
+struct Item;
+
+fn main() {}
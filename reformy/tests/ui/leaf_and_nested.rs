@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Order {
+    #[form(nested, leaf)]
+    item: String,
+}
+
+fn main() {}
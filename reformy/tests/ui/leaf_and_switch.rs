@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Order {
+    #[form(switch, leaf)]
+    gift: bool,
+}
+
+fn main() {}
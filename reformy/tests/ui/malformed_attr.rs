@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct User {
+    #[form = "nested"]
+    name: String,
+}
+
+fn main() {}
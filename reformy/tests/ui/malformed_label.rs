@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct User {
+    #[form(label = 5)]
+    name: String,
+}
+
+fn main() {}
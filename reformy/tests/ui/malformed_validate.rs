@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Order {
+    #[form(validate = "not_empty")]
+    note: String,
+}
+
+fn main() {}
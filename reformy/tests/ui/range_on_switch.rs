@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Settings {
+    #[form(switch, max = 1)]
+    muted: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Order {
+    #[form(skip, readonly)]
+    id: u64,
+    note: String,
+}
+
+fn main() {}
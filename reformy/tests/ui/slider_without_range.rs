@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Settings {
+    #[form(slider, min = 0)]
+    volume: u8,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+#[form(transparent)]
+struct Range(u32, u32);
+
+fn main() {}
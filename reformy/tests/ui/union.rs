@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+union Number {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
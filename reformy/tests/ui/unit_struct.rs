@@ -0,0 +1,6 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Marker;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+#[form(nested)]
+struct User {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct User {
+    #[form(nested, colour = "red")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+struct Post {
+    #[form(multiline(height = 5))]
+    body: String,
+}
+
+fn main() {}
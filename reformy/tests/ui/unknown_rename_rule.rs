@@ -0,0 +1,9 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+#[form(rename_all = "Shouty Case")]
+struct Order {
+    item_name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use reformy::FormRenderable;
+
+#[derive(FormRenderable)]
+enum Role {
+    #[form(hidden)]
+    Admin,
+    Guest,
+}
+
+fn main() {}
@@ -0,0 +1,90 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormErrors, FormRenderable, FormWidget};
+
+fn not_empty(text: &str) -> Result<(), String> {
+    if text.trim().is_empty() {
+        Err("must not be empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn passwords_match(signup: &Signup) -> Result<(), FormErrors> {
+    if signup.password == signup.confirm {
+        Ok(())
+    } else {
+        Err(FormErrors::at("confirm", "doesn't match the password"))
+    }
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Address {
+    #[form(validate = not_empty)]
+    street: String,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(validate_with = passwords_match)]
+struct Signup {
+    #[form(validate = not_empty)]
+    name: String,
+    address: Address,
+    password: String,
+    confirm: String,
+}
+
+fn signup(name: &str, street: &str, password: &str, confirm: &str) -> Signup {
+    Signup {
+        name: name.to_string(),
+        address: Address {
+            street: street.to_string(),
+        },
+        password: password.to_string(),
+        confirm: confirm.to_string(),
+    }
+}
+
+#[test]
+fn failing_validators_block_the_build_at_their_field() {
+    let mut form = Signup::form();
+    form.set_value(&signup(" ", "", "secret", "secret"));
+
+    let paths: Vec<String> = form
+        .try_build()
+        .unwrap_err()
+        .iter()
+        .map(|error| format!("{}: {}", error.path, error.message))
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "name: must not be empty",
+            "address.street: must not be empty"
+        ]
+    );
+
+    form.set_value(&signup("ada", "Main Street 1", "secret", "secret"));
+    assert_eq!(
+        form.try_build(),
+        Ok(signup("ada", "Main Street 1", "secret", "secret"))
+    );
+}
+
+#[test]
+fn container_validator_runs_once_the_fields_build() {
+    let mut form = Signup::form();
+    form.set_value(&signup("", "Main Street 1", "secret", "typo"));
+    assert_eq!(
+        form.try_build(),
+        Err(FormErrors::at("name", "must not be empty"))
+    );
+
+    form.set_value(&signup("ada", "Main Street 1", "secret", "typo"));
+    assert_eq!(
+        form.try_build(),
+        Err(FormErrors::at("confirm", "doesn't match the password"))
+    );
+
+    assert!(form.focus_first_error());
+    assert_eq!(form.focus_path(), FieldPath::from("confirm"));
+}
@@ -0,0 +1,93 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+enum Visitor {
+    Guest { name: String },
+    Member { id: u32 },
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+enum Shape {
+    Point,
+    Circle(u32),
+    Rect(u32, String),
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+#[test]
+fn left_and_right_in_a_variant_field_move_the_cursor() {
+    let mut form = Visitor::form();
+    form.set_focus("Guest.name");
+    for c in "ad".chars() {
+        form.input(key(Key::Char(c)));
+    }
+
+    form.input(key(Key::Left));
+    form.input(key(Key::Char('a')));
+    form.input(key(Key::Right));
+    form.input(key(Key::Left));
+
+    assert_eq!(
+        form.try_build(),
+        Ok(Visitor::Guest {
+            name: "aad".to_string()
+        })
+    );
+    assert_eq!(form.focus_path(), FieldPath::from("Guest.name"));
+}
+
+#[test]
+fn left_and_right_on_the_selector_switch_the_variant() {
+    let mut form = Visitor::form();
+    form.set_focus("Guest.name");
+    form.input(key(Key::Char('x')));
+    form.set_focus("");
+
+    assert!(form.input(key(Key::Right)));
+    assert_eq!(form.try_build(), Ok(Visitor::Member { id: 0 }));
+    assert!(form.input(key(Key::Left)));
+    assert_eq!(
+        form.try_build(),
+        Ok(Visitor::Guest {
+            name: "x".to_string()
+        })
+    );
+}
+
+#[test]
+fn tuple_variants_round_trip() {
+    let shapes = [
+        Shape::Point,
+        Shape::Circle(3),
+        Shape::Rect(4, "wide".to_string()),
+    ];
+
+    let mut form = Shape::form();
+    for shape in shapes {
+        assert_eq!(Shape::form_from(&shape).try_build(), Ok(shape));
+    }
+
+    for shape in [Shape::Rect(4, "wide".to_string()), Shape::Circle(7)] {
+        form.set_value(&shape);
+        assert_eq!(form.try_build(), Ok(shape));
+    }
+}
+
+#[test]
+fn tuple_variant_fields_are_built_from_their_forms() {
+    let mut form = Shape::form_from(&Shape::Rect(0, String::new()));
+    assert!(form.set_focus("Rect.1"));
+    for c in "tall".chars() {
+        form.input(key(Key::Char(c)));
+    }
+    assert_eq!(form.focus_path(), FieldPath::from("Rect.1"));
+    assert_eq!(form.try_build(), Ok(Shape::Rect(0, "tall".to_string())));
+}
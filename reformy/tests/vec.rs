@@ -0,0 +1,79 @@
+use reformy::FormRenderable;
+use reformy_core::{FieldPath, Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+#[form(transparent)]
+struct Page(#[form(multiline)] String);
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Notes {
+    pages: Vec<Page>,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Item {
+    name: String,
+    qty: u32,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Order {
+    items: Vec<Item>,
+}
+
+fn page(text: &str) -> Page {
+    Page(text.to_string())
+}
+
+fn alt(key: Key) -> Input {
+    Input {
+        key,
+        alt: true,
+        ..Input::default()
+    }
+}
+
+#[test]
+fn alt_arrows_reorder_multiline_items() {
+    let mut form = Notes::form();
+    form.set_value(&Notes {
+        pages: vec![page("first"), page("second\nline")],
+    });
+    form.set_focus("pages.1");
+
+    assert!(form.root_input(alt(Key::Up), false));
+    assert_eq!(
+        form.try_build().unwrap().pages,
+        [page("second\nline"), page("first")]
+    );
+
+    assert!(form.root_input(alt(Key::Down), false));
+    assert_eq!(
+        form.try_build().unwrap().pages,
+        [page("first"), page("second\nline")]
+    );
+}
+
+#[test]
+fn alt_arrows_reorder_struct_items_from_a_nested_field() {
+    let item = |name: &str, qty| Item {
+        name: name.to_string(),
+        qty,
+    };
+    let mut form = Order::form();
+    form.set_value(&Order {
+        items: vec![item("apple", 1), item("pear", 2)],
+    });
+    form.set_focus("items.1.qty");
+
+    assert!(form.root_input(alt(Key::Up), false));
+    assert_eq!(
+        form.try_build().unwrap().items,
+        [item("pear", 2), item("apple", 1)]
+    );
+    assert_eq!(form.focus_path(), FieldPath::from("items.0.qty"));
+
+    assert!(!form.root_input(alt(Key::Up), false));
+    assert_eq!(form.focus_path(), FieldPath::from("items.0.qty"));
+}
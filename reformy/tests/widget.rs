@@ -0,0 +1,45 @@
+use reformy::FormRenderable;
+use reformy_core::{Form, FormRenderable, FormWidget};
+use tui_textarea::{Input, Key};
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Login {
+    user: String,
+}
+
+#[derive(Debug, PartialEq, FormRenderable)]
+struct Port {
+    port: u16,
+}
+
+fn type_text(form: &mut dyn FormWidget, text: &str) {
+    for c in text.chars() {
+        form.input(Input {
+            key: Key::Char(c),
+            ..Input::default()
+        });
+    }
+}
+
+#[test]
+fn forms_of_different_types_share_a_widget_list() {
+    let mut widgets: Vec<Box<dyn FormWidget>> =
+        vec![Box::new(Login::form()), Box::new(Port::form())];
+    type_text(widgets[0].as_mut(), "ada");
+    type_text(widgets[1].as_mut(), "x");
+
+    let heights: Vec<u16> = widgets.iter().map(|form| form.form_height()).collect();
+    assert_eq!(heights, [2, 3]);
+}
+
+#[test]
+fn boxed_form_trait_object_still_builds() {
+    let mut form: Box<dyn Form<Output = Login>> = Box::new(Login::form());
+    type_text(form.as_mut(), "ada");
+    assert_eq!(
+        form.try_build(),
+        Ok(Login {
+            user: "ada".to_string()
+        })
+    );
+}